@@ -14,7 +14,7 @@ use std::cell::Cell;
 
 use iup::prelude::*;
 use iup::layout::{Radio, VBox};
-use iup::control::{Frame, Toggle, Label};
+use iup::control::{Frame, Toggle, ToggleState, Label};
 
 const RED: (u8, u8, u8)   = (255, 0, 0);
 const GREEN: (u8, u8, u8) = (0, 255, 0);
@@ -50,13 +50,13 @@ fn main() {
 
         // Setup the coloring toggles
         let toggle_d = Toggle::with_title("Default Color")
-                        .set_action(move |(_, state)| color_d.set(change_color(dialog, state, None)));
+                        .set_action(move |(_, state)| color_d.set(change_color(dialog, state == ToggleState::On, None)));
         let toggle_r = Toggle::with_title("Red Color")
-                        .set_action(move |(_, state)| color_r.set(change_color(dialog, state, Some(RED))));
+                        .set_action(move |(_, state)| color_r.set(change_color(dialog, state == ToggleState::On, Some(RED))));
         let toggle_g = Toggle::with_title("Green Color")
-                        .set_action(move |(_, state)| color_g.set(change_color(dialog, state, Some(GREEN))));
+                        .set_action(move |(_, state)| color_g.set(change_color(dialog, state == ToggleState::On, Some(GREEN))));
         let toggle_b = Toggle::with_title("Blue Color")
-                        .set_action(move |(_, state)| color_b.set(change_color(dialog, state, Some(BLUE))));
+                        .set_action(move |(_, state)| color_b.set(change_color(dialog, state == ToggleState::On, Some(BLUE))));
 
         // Setup the radio of mutually exclusive toggles
         let mut radio = Radio::new(
@@ -74,7 +74,7 @@ fn main() {
         let toggle = Toggle::with_title("Allow Colors")
                         .set_attrib("VALUE", "YES")
                         .set_action(move |(_, state)| {
-                            if state {
+                            if state == ToggleState::On {
                                 radio.set_attrib("ACTIVE", "YES");
                                 color.set(change_color(dialog, true, color.get()));
                             } else {
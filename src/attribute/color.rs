@@ -0,0 +1,44 @@
+//! Colors for color attributes.
+
+/// A RGBA color.
+///
+/// Most IUP color attributes have no alpha channel, in which case `a` is ignored when setting
+/// the attribute and is `255` when getting it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    /// Creates an opaque color from its red, green and blue components.
+    pub fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b, a: 255 }
+    }
+
+    /// Creates a color from its red, green, blue and alpha components.
+    pub fn rgba(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color { r, g, b, a }
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from(rgb: (u8, u8, u8)) -> Color {
+        Color::rgb(rgb.0, rgb.1, rgb.2)
+    }
+}
+
+impl From<(u8, u8, u8, u8)> for Color {
+    fn from(rgba: (u8, u8, u8, u8)) -> Color {
+        Color::rgba(rgba.0, rgba.1, rgba.2, rgba.3)
+    }
+}
+
+/// Converts from a `0xRRGGBB` value into an opaque color.
+impl From<u32> for Color {
+    fn from(rgb: u32) -> Color {
+        Color::rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+    }
+}
@@ -0,0 +1,390 @@
+//! Typed access to common attributes.
+//!
+//! Any attribute can be accessed by its name using `Element::set_attrib` and `Element::attrib`,
+//! the traits in this module just provide a typed and discoverable interface to the attributes
+//! shared by many elements. Each element implements only the traits it supports.
+//!
+//! See also the [IUP Attributes Guide][1].
+//! [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/attrib_guide.html
+use libc::c_char;
+
+use Element;
+use Handle;
+
+pub mod color;
+pub use self::color::Color;
+
+pub mod size;
+pub use self::size::Size;
+
+/// Elements that have a title, such as buttons, labels, frames and dialogs.
+pub trait TitleAttribute : Element {
+    /// Sets the title of the element.
+    ///
+    /// This is a shortcut to the TITLE attribute.
+    fn set_title<S: Into<String>>(&mut self, title: S) -> Self {
+        self.set_attrib("TITLE", title)
+    }
+
+    /// Gets the title of the element.
+    fn title(&self) -> Option<String> {
+        self.attrib("TITLE")
+    }
+}
+
+/// Elements that have a background color.
+pub trait BgColorAttribute : Element {
+    /// Sets the background color of the element.
+    ///
+    /// This is a shortcut to the BGCOLOR attribute, which can also be set as a `"r g b"` string.
+    fn set_bgcolor<C: Into<Color>>(&mut self, color: C) -> Self {
+        self.set_attrib_color("BGCOLOR", color)
+    }
+
+    /// Gets the background color of the element.
+    fn bgcolor(&self) -> Option<Color> {
+        self.attrib_color("BGCOLOR")
+    }
+}
+
+/// Elements that have a foreground (usually text) color.
+pub trait FgColorAttribute : Element {
+    /// Sets the foreground color of the element.
+    ///
+    /// This is a shortcut to the FGCOLOR attribute, which can also be set as a `"r g b"` string.
+    fn set_fgcolor<C: Into<Color>>(&mut self, color: C) -> Self {
+        self.set_attrib_color("FGCOLOR", color)
+    }
+
+    /// Gets the foreground color of the element.
+    fn fgcolor(&self) -> Option<Color> {
+        self.attrib_color("FGCOLOR")
+    }
+}
+
+/// Elements that can show a tooltip, that is, most native controls.
+pub trait TipAttribute : Element {
+    /// Sets the text of the tooltip shown when the mouse stays over the element.
+    ///
+    /// This is a shortcut to the TIP attribute.
+    fn set_tip<S: Into<String>>(&mut self, text: S) -> Self {
+        self.set_attrib("TIP", text)
+    }
+
+    /// Gets the text of the tooltip.
+    fn tip(&self) -> Option<String> {
+        self.attrib("TIP")
+    }
+
+    /// Shows or hides the tooltip right away. Only works after the element is mapped.
+    fn set_tip_visible(&mut self, on: bool) -> Self {
+        self.set_attrib("TIPVISIBLE", if on { "YES" } else { "NO" })
+    }
+
+    /// Checks whether the tooltip is currently visible.
+    fn tip_visible(&self) -> bool {
+        self.attrib("TIPVISIBLE").map(|s| s == "YES").unwrap_or(false)
+    }
+
+    /// Sets the time in milliseconds the tooltip stays visible. Default: 5000.
+    fn set_tip_delay(&mut self, ms: u32) -> Self {
+        self.set_attrib("TIPDELAY", ms.to_string())
+    }
+
+    /// Gets the time in milliseconds the tooltip stays visible.
+    fn tip_delay(&self) -> u32 {
+        self.attrib_parse("TIPDELAY").unwrap_or(5000)
+    }
+
+    /// Shows the tooltip as a balloon. Windows only and must be set before the element is mapped.
+    fn set_tip_balloon(&mut self, on: bool) -> Self {
+        self.set_attrib("TIPBALLOON", if on { "YES" } else { "NO" })
+    }
+
+    /// Sets the title of the balloon tooltip. Windows only.
+    fn set_tip_balloon_title<S: Into<String>>(&mut self, title: S) -> Self {
+        self.set_attrib("TIPBALLOONTITLE", title)
+    }
+}
+
+/// Elements that can receive files dropped from the system file manager.
+///
+/// See also the `DropFilesCb` callback.
+pub trait DropTargetAttribute : Element {
+    /// Enables or disables the element as a target for dropped files.
+    ///
+    /// This is a shortcut to the DROPFILESTARGET attribute. It is automatically enabled when
+    /// `DropFilesCb` is set before the element is mapped.
+    fn set_drop_target(&mut self, on: bool) -> Self {
+        self.set_attrib("DROPFILESTARGET", if on { "YES" } else { "NO" })
+    }
+
+    /// Checks whether the element accepts dropped files.
+    fn drop_target(&self) -> bool {
+        self.attrib("DROPFILESTARGET").map(|s| s == "YES").unwrap_or(false)
+    }
+}
+
+/// Elements that have a user defined size, that is, every widget.
+pub trait SizeAttribute : Element {
+    /// Sets the size of the element in units proportional to the size of a character.
+    ///
+    /// This is a shortcut to the SIZE attribute. By default the natural size of the element is
+    /// used, which is also the case of any dimension that is `None` in the given size.
+    fn set_size(&mut self, size: Size) -> Self {
+        self.set_attrib("SIZE", size.to_string())
+    }
+
+    /// Gets the size of the element in units proportional to the size of a character.
+    fn size(&self) -> Size {
+        self.attrib_parse("SIZE").unwrap_or_default()
+    }
+
+    /// Sets the size of the element in pixels.
+    ///
+    /// This is a shortcut to the RASTERSIZE attribute. By default the natural size of the
+    /// element is used, which is also the case of any dimension that is `None` in the given size.
+    fn set_rastersize(&mut self, size: Size) -> Self {
+        self.set_attrib("RASTERSIZE", size.to_string())
+    }
+
+    /// Gets the size of the element in pixels.
+    fn rastersize(&self) -> Size {
+        self.attrib_parse("RASTERSIZE").unwrap_or_default()
+    }
+}
+
+/// The orientation of an element, such as separators, progress bars and valuators.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Orientation {
+    Vertical,
+    Horizontal,
+}
+
+impl Orientation {
+    #[doc(hidden)]
+    pub fn as_cstr(self) -> *const c_char {
+        use self::Orientation::*;
+        match self {
+            Vertical => cstr!("VERTICAL"),
+            Horizontal => cstr!("HORIZONTAL"),
+        }
+    }
+}
+
+/// The horizontal alignment of an element inside its container.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum HorizontalAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+impl HorizontalAlignment {
+    #[doc(hidden)]
+    pub fn as_str(self) -> &'static str {
+        use self::HorizontalAlignment::*;
+        match self {
+            Left => "ALEFT",
+            Center => "ACENTER",
+            Right => "ARIGHT",
+        }
+    }
+}
+
+/// The vertical alignment of an element inside its container.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VerticalAlignment {
+    Top,
+    Center,
+    Bottom,
+}
+
+impl VerticalAlignment {
+    #[doc(hidden)]
+    pub fn as_str(self) -> &'static str {
+        use self::VerticalAlignment::*;
+        match self {
+            Top => "ATOP",
+            Center => "ACENTER",
+            Bottom => "ABOTTOM",
+        }
+    }
+}
+
+/// The scrollbars an element may show.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ScrollBar {
+    /// No scrollbars.
+    No,
+    /// Only the horizontal scrollbar.
+    Horizontal,
+    /// Only the vertical scrollbar.
+    Vertical,
+    /// Both the horizontal and vertical scrollbars.
+    Both,
+}
+
+impl ScrollBar {
+    #[doc(hidden)]
+    pub fn as_cstr(self) -> *const c_char {
+        use self::ScrollBar::*;
+        match self {
+            No => cstr!("NO"),
+            Horizontal => cstr!("HORIZONTAL"),
+            Vertical => cstr!("VERTICAL"),
+            Both => cstr!("YES"),
+        }
+    }
+}
+
+/// Elements that can show scrollbars, such as canvases and multiline texts.
+pub trait ScrollBarAttribute : Element {
+    /// Sets which scrollbars the element shows.
+    ///
+    /// This is a shortcut to the SCROLLBAR attribute and can only be set before the element
+    /// is mapped.
+    fn set_scrollbar(&mut self, scrollbar: ScrollBar) -> Self {
+        self.set_attrib_data("SCROLLBAR", scrollbar.as_cstr() as *const _)
+    }
+
+    /// Gets which scrollbars the element shows.
+    fn scrollbar(&self) -> ScrollBar {
+        match self.attrib("SCROLLBAR").as_ref().map(|s| s.as_ref()) {
+            Some("YES") => ScrollBar::Both,
+            Some("HORIZONTAL") => ScrollBar::Horizontal,
+            Some("VERTICAL") => ScrollBar::Vertical,
+            _ => ScrollBar::No,
+        }
+    }
+}
+
+/// The mouse cursor shown over an element.
+#[derive(Debug, Copy, Clone)]
+pub enum Cursor {
+    /// No cursor is shown.
+    None,
+    Arrow,
+    Busy,
+    Cross,
+    Hand,
+    Help,
+    Move,
+    Pen,
+    Text,
+    UpArrow,
+    ResizeN,
+    ResizeS,
+    ResizeNS,
+    ResizeW,
+    ResizeE,
+    ResizeWE,
+    ResizeNE,
+    ResizeSW,
+    ResizeNW,
+    ResizeSE,
+    SplitterHoriz,
+    SplitterVert,
+    /// A cursor made from an image element, its HOTSPOT attribute sets the active pixel.
+    Custom(Handle),
+}
+
+impl Cursor {
+    #[doc(hidden)]
+    pub fn as_str(self) -> Option<&'static str> {
+        use self::Cursor::*;
+        Some(match self {
+            None => "NONE",
+            Arrow => "ARROW",
+            Busy => "BUSY",
+            Cross => "CROSS",
+            Hand => "HAND",
+            Help => "HELP",
+            Move => "MOVE",
+            Pen => "PEN",
+            Text => "TEXT",
+            UpArrow => "UPARROW",
+            ResizeN => "RESIZE_N",
+            ResizeS => "RESIZE_S",
+            ResizeNS => "RESIZE_NS",
+            ResizeW => "RESIZE_W",
+            ResizeE => "RESIZE_E",
+            ResizeWE => "RESIZE_WE",
+            ResizeNE => "RESIZE_NE",
+            ResizeSW => "RESIZE_SW",
+            ResizeNW => "RESIZE_NW",
+            ResizeSE => "RESIZE_SE",
+            SplitterHoriz => "SPLITTER_HORIZ",
+            SplitterVert => "SPLITTER_VERT",
+            Custom(_) => return Option::None,
+        })
+    }
+
+    #[doc(hidden)]
+    pub fn from_name(s: &str) -> Option<Cursor> {
+        use self::Cursor::*;
+        Some(match s {
+            "NONE" | "NULL" => None,
+            "ARROW" => Arrow,
+            "BUSY" => Busy,
+            "CROSS" => Cross,
+            "HAND" => Hand,
+            "HELP" => Help,
+            "MOVE" => Move,
+            "PEN" => Pen,
+            "TEXT" => Text,
+            "UPARROW" => UpArrow,
+            "RESIZE_N" => ResizeN,
+            "RESIZE_S" => ResizeS,
+            "RESIZE_NS" => ResizeNS,
+            "RESIZE_W" => ResizeW,
+            "RESIZE_E" => ResizeE,
+            "RESIZE_WE" => ResizeWE,
+            "RESIZE_NE" => ResizeNE,
+            "RESIZE_SW" => ResizeSW,
+            "RESIZE_NW" => ResizeNW,
+            "RESIZE_SE" => ResizeSE,
+            "SPLITTER_HORIZ" => SplitterHoriz,
+            "SPLITTER_VERT" => SplitterVert,
+            _ => return Option::None,
+        })
+    }
+}
+
+/// Custom cursors are equal when they use the same image element.
+impl PartialEq for Cursor {
+    fn eq(&self, other: &Cursor) -> bool {
+        match (*self, *other) {
+            (Cursor::Custom(a), Cursor::Custom(b)) => a.raw() == b.raw(),
+            (a, b) => a.as_str().is_some() && a.as_str() == b.as_str(),
+        }
+    }
+}
+
+impl Eq for Cursor {}
+
+/// Elements that can change the mouse cursor, such as canvases and dialogs.
+pub trait CursorAttribute : Element {
+    /// Sets the mouse cursor shown when the mouse is over the element.
+    ///
+    /// This is a shortcut to the CURSOR attribute. Custom cursors are associated with the image
+    /// element through its handle name, which is created if the image has none.
+    fn set_cursor(&mut self, cursor: Cursor) -> Self {
+        match cursor {
+            Cursor::Custom(image) => self.set_attrib_handle("CURSOR", image),
+            named => self.set_attrib("CURSOR", named.as_str().unwrap()),
+        }
+    }
+
+    /// Gets the mouse cursor shown when the mouse is over the element.
+    ///
+    /// The default cursor is `Cursor::Arrow`.
+    fn cursor(&self) -> Cursor {
+        match self.attrib("CURSOR") {
+            Some(name) => Cursor::from_name(&name)
+                            .or_else(|| self.attrib_handle("CURSOR").map(Cursor::Custom))
+                            .unwrap_or(Cursor::Arrow),
+            None => Cursor::Arrow,
+        }
+    }
+}
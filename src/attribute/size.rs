@@ -0,0 +1,65 @@
+//! Sizes for size attributes.
+use std::fmt;
+use std::str::FromStr;
+
+/// The size of an element in the `"WxH"` format used by IUP.
+///
+/// A `None` dimension means it is not defined and the natural size of the element is used for it.
+///
+/// The unit depends on the attribute, SIZE uses a fraction of the font size (1/4 of the average
+/// character width and 1/8 of the character height) while RASTERSIZE uses pixels.
+///
+/// # Example
+/// ```
+/// use iup::attribute::Size;
+///
+/// assert_eq!("80x".parse(), Ok(Size::new(Some(80), None)));
+/// assert_eq!(Size::new(None, Some(20)).to_string(), "x20");
+/// ```
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct Size {
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+impl Size {
+    /// Creates a size from its dimensions.
+    pub fn new(width: Option<i32>, height: Option<i32>) -> Size {
+        Size { width, height }
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(w) = self.width {
+            write!(fmt, "{}", w)?;
+        }
+        write!(fmt, "x")?;
+        if let Some(h) = self.height {
+            write!(fmt, "{}", h)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when a string does not follow the `"WxH"` format.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParseSizeError;
+
+impl FromStr for Size {
+    type Err = ParseSizeError;
+
+    fn from_str(s: &str) -> Result<Size, ParseSizeError> {
+        fn dimension(s: &str) -> Result<Option<i32>, ParseSizeError> {
+            match s.trim() {
+                "" => Ok(None),
+                s => s.parse().map(Some).map_err(|_| ParseSizeError),
+            }
+        }
+
+        let mut parts = s.splitn(2, 'x');
+        let width = parts.next().unwrap_or("");
+        let height = parts.next().ok_or(ParseSizeError)?;
+        Ok(Size::new(dimension(width)?, dimension(height)?))
+    }
+}
@@ -1,7 +1,7 @@
-//! Mouse button presses callback.
+//! Mouse button presses, motion and wheel callbacks.
 use iup_sys;
 use std::fmt;
-use libc::{c_char, c_int};
+use libc::{c_char, c_int, c_float};
 use callback::IntoRust;
 
 /// Mouse buttons.
@@ -40,63 +40,81 @@ pub enum MouseButtonState {
     Pressed,
 }
 
-// TODO a way to unallow the user to move/copy instances of this type as the char buffer will
-// be invalid after the callback exits.
+/// Length of the IUP status string, not counting the null terminator.
+const STATUS_SIZE: usize = 10;
+
 /// The state of mouse buttons and some keyboard buttons.
-pub struct KeyStates(*const c_char);
+///
+/// This is a copy of the IUP status string, thus it can be kept after the callback returns.
+/// It can also be built from a status string in the IUP format, such as `"S 1       "` for a
+/// left button press while shift is down.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct KeyStates([c_char; STATUS_SIZE]);
 
 impl KeyStates {
+    /// Copies a status string, missing characters are taken as not pressed.
+    unsafe fn from_ptr(status: *const c_char) -> KeyStates {
+        let mut states = [b' ' as c_char; STATUS_SIZE];
+        for (i, state) in states.iter_mut().enumerate() {
+            match *status.add(i) {
+                0 => break,
+                c => *state = c,
+            }
+        }
+        KeyStates(states)
+    }
+
     /// Whether this state have a SHIFT key pressed.
     #[inline(always)]
     pub fn is_shift(&self) -> bool {
-        unsafe { iup_sys::iup_isshift(self.0) }
+        unsafe { iup_sys::iup_isshift(self.0.as_ptr()) }
     }
     /// Whether this state have a CONTROL key pressed.
     #[inline(always)]
     pub fn is_control(&self) -> bool {
-        unsafe { iup_sys::iup_iscontrol(self.0) }
+        unsafe { iup_sys::iup_iscontrol(self.0.as_ptr()) }
     }
     /// Whether this state have a ALT key pressed.
     #[inline(always)]
     pub fn is_alt(&self) -> bool {
-        unsafe { iup_sys::iup_isalt(self.0) }
+        unsafe { iup_sys::iup_isalt(self.0.as_ptr()) }
     }
     /// Whether this state have the system key pressed.
     ///
     /// The system key in Windows is the *Windows key* and in Mac is the *Apple key*.
     #[inline(always)]
     pub fn is_sys(&self) -> bool {
-        unsafe { iup_sys::iup_issys(self.0) }
+        unsafe { iup_sys::iup_issys(self.0.as_ptr()) }
     }
     /// Whether this state have the specified button in the callback doubly pressed.
     #[inline(always)]
     pub fn is_double(&self) -> bool {
-        unsafe { iup_sys::iup_isdouble(self.0) }
+        unsafe { iup_sys::iup_isdouble(self.0.as_ptr()) }
     }
     /// Whether this state have the left mouse button pressed.
     #[inline(always)]
     pub fn is_button1(&self) -> bool {
-        unsafe { iup_sys::iup_isbutton1(self.0) }
+        unsafe { iup_sys::iup_isbutton1(self.0.as_ptr()) }
     }
     /// Whether this state have the middle mouse button pressed.
     #[inline(always)]
     pub fn is_button2(&self) -> bool {
-        unsafe { iup_sys::iup_isbutton2(self.0) }
+        unsafe { iup_sys::iup_isbutton2(self.0.as_ptr()) }
     }
     /// Whether this state have the right mouse button pressed.
     #[inline(always)]
     pub fn is_button3(&self) -> bool {
-        unsafe { iup_sys::iup_isbutton3(self.0) }
+        unsafe { iup_sys::iup_isbutton3(self.0.as_ptr()) }
     }
     /// Whether this state have the additional mouse button 1 pressed.
     #[inline(always)]
     pub fn is_button4(&self) -> bool {
-        unsafe { iup_sys::iup_isbutton4(self.0) }
+        unsafe { iup_sys::iup_isbutton4(self.0.as_ptr()) }
     }
     /// Whether this state have the additional mouse button 2 pressed.
     #[inline(always)]
     pub fn is_button5(&self) -> bool {
-        unsafe { iup_sys::iup_isbutton5(self.0) }
+        unsafe { iup_sys::iup_isbutton5(self.0.as_ptr()) }
     }
 }
 
@@ -111,9 +129,22 @@ impl fmt::Debug for KeyStates {
     }
 }
 
+impl<'a> From<&'a str> for KeyStates {
+    fn from(status: &'a str) -> KeyStates {
+        let mut states = [b' ' as c_char; STATUS_SIZE];
+        for (state, &c) in states.iter_mut().zip(status.as_bytes()) {
+            *state = c as c_char;
+        }
+        KeyStates(states)
+    }
+}
+
 impl IntoRust<KeyStates> for *mut c_char {
+    // SAFETY: `into_rust` is only called by the `impl_callback!` listeners, on the status
+    // pointer IUP passes to the callback, which is valid for the duration of the call.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
     fn into_rust(self) -> KeyStates {
-        KeyStates(self)
+        unsafe { KeyStates::from_ptr(self) }
     }
 }
 
@@ -180,3 +211,24 @@ impl_callback! {
     }
 }
 
+impl_callback! {
+    /// Action generated when the mouse wheel is rotated.
+    ///
+    /// The `f32` parameter is the amount the wheel was rotated in notches, positive when rotated
+    /// away from the user *(scroll up)*. Smooth scrolling devices such as touchpads may report
+    /// fractional values.
+    ///
+    /// The `i32` parameters are the x,y position in the canvas where the event has occurred,
+    /// in pixels.
+    ///
+    /// The `KeyStates` parameter is the state of the mouse buttons and some keyboard keys at
+    /// the moment the event is generated.
+    ///
+    /// [Learn more](http://webserver2.tecgraf.puc-rio.br/iup/en/call/iup_wheel_cb.html).
+    pub trait WheelCb where Self: Element {
+        let name = "WHEEL_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, delta: c_float, x: c_int, y: c_int, status: *mut c_char) -> CallbackReturn;
+        fn set_wheel_cb<F: Callback(Self, f32, i32, i32, KeyStates)>(&mut self, cb: F) -> Self;
+        fn remove_wheel_cb(&mut self) -> Option<Box<_>>;
+    }
+}
@@ -1,5 +1,6 @@
-use libc::c_char;
+use libc::{c_char, c_int, c_float};
 use std::path::PathBuf;
+use callback::IntoRust;
 
 //
 // The following regex can be used to convert from doc comments to attrib comments:
@@ -25,6 +26,13 @@ impl_callback! {
     #[doc="Action generated when there are no events or messages to be processed."]
     #[doc=""]
     #[doc="Often used to perform background operations."]
+    #[doc=""]
+    #[doc="Only one idle callback can be active at a time, setting a new one replaces the previous."]
+    #[doc=""]
+    #[doc="While set, the callback is called repeatedly as long as it returns `CallbackReturn::Default`"]
+    #[doc="which keeps the CPU busy. Keep each call short, since events are only processed between"]
+    #[doc="calls, and remove the callback (or return `CallbackReturn::Ignore`, which also removes it)"]
+    #[doc="when there's no more work to do. Returning `CallbackReturn::Close` exits the main loop."]
     pub fn set_idle<F: Callback()>(cb: F);
     #[doc="Removes a previosly set up idle_action callback."]
     pub fn remove_idle() -> Option<Box<_>>;
@@ -217,7 +225,7 @@ impl_callback! {
     #[doc="each file. "]
     #[doc=""]
     #[doc="If defined after the element is mapped then the attribute DROPFILESTARGET must be set"]
-    #[doc="to YES."]
+    #[doc="to YES, see `DropTargetAttribute::set_drop_target`."]
     #[doc=""]
     #[doc="The third parameter of the callback is the number index of the dropped file. If several"]
     #[doc="files are dropped, it is the index of the dropped file starting from *total-1* to *0*."]
@@ -242,14 +250,15 @@ impl_callback! {
     #[doc="Called just before a dialog is closed when the user clicks the close button of the title bar"]
     #[doc="or an equivalent action."]
     #[doc=""]
+    #[doc="Returning `CallbackReturn::Default` lets the dialog be closed."]
     #[doc="`CallbackReturn::Close` will be processed. If `CallbackReturn::Ignore`, it prevents the dialog"]
     #[doc="from being closed. If you destroy the dialog in this callback, you must"]
     #[doc="return `CallbackReturn::Ignore`."]
     pub trait CloseCb where Self: Element {
         let name = "CLOSE_CB";
         extern fn listener(ih: *mut iup_sys::Ihandle) -> CallbackReturn;
-        fn set_move_cb<F: Callback(Self)>(&mut self, cb: F) -> Self;
-        fn remove_move_cb(&mut self) -> Option<Box<_>>;
+        fn set_close_cb<F: Callback(Self)>(&mut self, cb: F) -> Self;
+        fn remove_close_cb(&mut self) -> Option<Box<_>>;
     }
 }
 
@@ -269,7 +278,73 @@ impl_callback! {
     pub trait ResizeCb where Self: Element {
         let name = "RESIZE_CB";
         extern fn listener(ih: *mut iup_sys::Ihandle, w: c_int, h: c_int) -> CallbackReturn;
-        fn set_move_cb<F: Callback(Self, i32, i32)>(&mut self, cb: F) -> Self;
-        fn remove_move_cb(&mut self) -> Option<Box<_>>;
+        fn set_resize_cb<F: Callback(Self, i32, i32)>(&mut self, cb: F) -> Self;
+        fn remove_resize_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
+/// The scrollbar operation that generated a `ScrollCb` callback.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ScrollOp {
+    /// Line up in the vertical scrollbar.
+    LineUp,
+    /// Line down in the vertical scrollbar.
+    LineDown,
+    /// Page up in the vertical scrollbar.
+    PageUp,
+    /// Page down in the vertical scrollbar.
+    PageDown,
+    /// The vertical thumb was released at a new position.
+    PosV,
+    /// The vertical thumb is being dragged.
+    DragV,
+    /// Column left in the horizontal scrollbar.
+    LineLeft,
+    /// Column right in the horizontal scrollbar.
+    LineRight,
+    /// Page left in the horizontal scrollbar.
+    PageLeft,
+    /// Page right in the horizontal scrollbar.
+    PageRight,
+    /// The horizontal thumb was released at a new position.
+    PosH,
+    /// The horizontal thumb is being dragged.
+    DragH,
+    /// An operation code not known by this binding.
+    Unknown(i32),
+}
+
+impl IntoRust<ScrollOp> for c_int {
+    fn into_rust(self) -> ScrollOp {
+        match self {
+            iup_sys::IUP_SBUP => ScrollOp::LineUp,
+            iup_sys::IUP_SBDN => ScrollOp::LineDown,
+            iup_sys::IUP_SBPGUP => ScrollOp::PageUp,
+            iup_sys::IUP_SBPGDN => ScrollOp::PageDown,
+            iup_sys::IUP_SBPOSV => ScrollOp::PosV,
+            iup_sys::IUP_SBDRAGV => ScrollOp::DragV,
+            iup_sys::IUP_SBLEFT => ScrollOp::LineLeft,
+            iup_sys::IUP_SBRIGHT => ScrollOp::LineRight,
+            iup_sys::IUP_SBPGLEFT => ScrollOp::PageLeft,
+            iup_sys::IUP_SBPGRIGHT => ScrollOp::PageRight,
+            iup_sys::IUP_SBPOSH => ScrollOp::PosH,
+            iup_sys::IUP_SBDRAGH => ScrollOp::DragH,
+            op => ScrollOp::Unknown(op),
+        }
+    }
+}
+
+impl_callback! {
+    #[doc="Called when some manipulation is made to the scrollbar."]
+    #[doc=""]
+    #[doc="The `ScrollOp` parameter is the operation performed and the `f32` parameters are the"]
+    #[doc="new thumb position of the horizontal and vertical scrollbars (POSX and POSY)."]
+    #[doc=""]
+    #[doc="When set, the canvas ACTION callback is not called automatically while scrolling."]
+    pub trait ScrollCb where Self: Element {
+        let name = "SCROLL_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, op: c_int, posx: c_float, posy: c_float) -> CallbackReturn;
+        fn set_scroll_cb<F: Callback(Self, ScrollOp, f32, f32)>(&mut self, cb: F) -> Self;
+        fn remove_scroll_cb(&mut self) -> Option<Box<_>>;
     }
 }
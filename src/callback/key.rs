@@ -0,0 +1,340 @@
+//! Keyboard callbacks.
+use iup_sys;
+use std::char;
+use std::collections::HashMap;
+use std::any::Any;
+use std::ffi::CString;
+use std::mem;
+use std::ptr;
+use libc::c_int;
+
+use Element;
+use callback::{Callback, IntoRust};
+
+/// Keys reported by the `KAnyCb` callback.
+///
+/// Key combinations involving Ctrl, Alt or the system key, as well as any key without a
+/// variant, are reported as `Unknown` with the raw IUP key code.
+///
+/// IUP reports the numeric keypad keys with the same codes of the main keyboard keys, e.g. the
+/// keypad `5` is `Key::Char('5')` and the keypad arrows are `Key::Left`, `Key::Up` and so on,
+/// except for the keypad middle key when NumLock is off, which is `Key::Middle`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Key {
+    /// A printable character such as letters, digits, punctuation and space.
+    Char(char),
+    /// A function key from F1 to F12.
+    F(u8),
+    BackSpace,
+    Tab,
+    LineFeed,
+    Enter,
+    Esc,
+    Pause,
+    Home,
+    End,
+    Left,
+    Up,
+    Right,
+    Down,
+    Middle,
+    PageUp,
+    PageDown,
+    Insert,
+    Delete,
+    Print,
+    Menu,
+    LeftShift,
+    RightShift,
+    LeftCtrl,
+    RightCtrl,
+    LeftAlt,
+    RightAlt,
+    CapsLock,
+    NumLock,
+    ScrollLock,
+    /// Any other key code.
+    Unknown(i32),
+}
+
+impl Key {
+    /// Converts from a IUP key code, as defined in `iupkey.h`.
+    pub fn from_raw(code: i32) -> Key {
+        use self::Key::*;
+        match code {
+            0x08 => BackSpace,
+            0x09 => Tab,
+            0x0A => LineFeed,
+            0x0D => Enter,
+            0x20..=0x7E => Char(char::from_u32(code as u32).unwrap()),
+            0xFF13 => Pause,
+            0xFF1B => Esc,
+            0xFF50 => Home,
+            0xFF51 => Left,
+            0xFF52 => Up,
+            0xFF53 => Right,
+            0xFF54 => Down,
+            0xFF0B => Middle,
+            0xFF55 => PageUp,
+            0xFF56 => PageDown,
+            0xFF57 => End,
+            0xFF61 => Print,
+            0xFF63 => Insert,
+            0xFF67 => Menu,
+            0xFFFF => Delete,
+            0xFFBE..=0xFFC9 => F((code - 0xFFBE + 1) as u8),
+            0xFFE1 => LeftShift,
+            0xFFE2 => RightShift,
+            0xFFE3 => LeftCtrl,
+            0xFFE4 => RightCtrl,
+            0xFFE5 => CapsLock,
+            0xFFE9 => LeftAlt,
+            0xFFEA => RightAlt,
+            0xFF7F => NumLock,
+            0xFF14 => ScrollLock,
+            _ => Unknown(code),
+        }
+    }
+
+    /// Converts into a IUP key code, as defined in `iupkey.h`.
+    ///
+    /// # Panics
+    /// Panics if the key is a `Char` outside the printable ASCII range or a `F` outside the range
+    /// from F1 to F12, since those have no IUP key code.
+    pub fn to_raw(self) -> i32 {
+        use self::Key::*;
+        match self {
+            BackSpace => 0x08,
+            Tab => 0x09,
+            LineFeed => 0x0A,
+            Enter => 0x0D,
+            Char(c @ ' '..='~') => c as i32,
+            Char(c) => panic!("no IUP key code for the character {:?}", c),
+            Pause => 0xFF13,
+            Esc => 0xFF1B,
+            Home => 0xFF50,
+            Left => 0xFF51,
+            Up => 0xFF52,
+            Right => 0xFF53,
+            Down => 0xFF54,
+            Middle => 0xFF0B,
+            PageUp => 0xFF55,
+            PageDown => 0xFF56,
+            End => 0xFF57,
+            Print => 0xFF61,
+            Insert => 0xFF63,
+            Menu => 0xFF67,
+            Delete => 0xFFFF,
+            F(n @ 1..=12) => 0xFFBE + n as i32 - 1,
+            F(n) => panic!("no IUP key code for F{}", n),
+            LeftShift => 0xFFE1,
+            RightShift => 0xFFE2,
+            LeftCtrl => 0xFFE3,
+            RightCtrl => 0xFFE4,
+            CapsLock => 0xFFE5,
+            LeftAlt => 0xFFE9,
+            RightAlt => 0xFFEA,
+            NumLock => 0xFF7F,
+            ScrollLock => 0xFF14,
+            Unknown(code) => code,
+        }
+    }
+
+    /// Gets the name of the IUP callback called when this key is pressed, e.g. `"K_F1"`.
+    ///
+    /// Returns `None` for keys with no IUP key code, including every `Unknown` key.
+    pub fn callback_name(self) -> Option<String> {
+        use self::Key::*;
+        let name = match self {
+            Char(c @ 'a'..='z') | Char(c @ 'A'..='Z') | Char(c @ '0'..='9') => {
+                return Some(format!("K_{}", c))
+            },
+            Char(' ') => "SP",
+            Char('!') => "exclam",
+            Char('"') => "quotedbl",
+            Char('#') => "numbersign",
+            Char('$') => "dollar",
+            Char('%') => "percent",
+            Char('&') => "ampersand",
+            Char('\'') => "apostrophe",
+            Char('(') => "parentleft",
+            Char(')') => "parentright",
+            Char('*') => "asterisk",
+            Char('+') => "plus",
+            Char(',') => "comma",
+            Char('-') => "minus",
+            Char('.') => "period",
+            Char('/') => "slash",
+            Char(':') => "colon",
+            Char(';') => "semicolon",
+            Char('<') => "less",
+            Char('=') => "equal",
+            Char('>') => "greater",
+            Char('?') => "question",
+            Char('@') => "at",
+            Char('[') => "bracketleft",
+            Char('\\') => "backslash",
+            Char(']') => "bracketright",
+            Char('^') => "circum",
+            Char('_') => "underscore",
+            Char('`') => "grave",
+            Char('{') => "braceleft",
+            Char('|') => "bar",
+            Char('}') => "braceright",
+            Char('~') => "tilde",
+            Char(_) => return None,
+            F(n @ 1..=12) => return Some(format!("K_F{}", n)),
+            F(_) => return None,
+            BackSpace => "BS",
+            Tab => "TAB",
+            LineFeed => "LF",
+            Enter => "CR",
+            Esc => "ESC",
+            Pause => "PAUSE",
+            Home => "HOME",
+            End => "END",
+            Left => "LEFT",
+            Up => "UP",
+            Right => "RIGHT",
+            Down => "DOWN",
+            Middle => "MIDDLE",
+            PageUp => "PGUP",
+            PageDown => "PGDN",
+            Insert => "INS",
+            Delete => "DEL",
+            Print => "Print",
+            Menu => "Menu",
+            LeftShift => "LSHIFT",
+            RightShift => "RSHIFT",
+            LeftCtrl => "LCTRL",
+            RightCtrl => "RCTRL",
+            LeftAlt => "LALT",
+            RightAlt => "RALT",
+            CapsLock => "CAPS",
+            NumLock => "NUM",
+            ScrollLock => "SCROLL",
+            Unknown(_) => return None,
+        };
+        Some(format!("K_{}", name))
+    }
+}
+
+impl IntoRust<Key> for c_int {
+    fn into_rust(self) -> Key {
+        Key::from_raw(self)
+    }
+}
+
+impl_callback! {
+    /// Action generated when a keyboard event occurs.
+    ///
+    /// The callback is called on the element with the keyboard focus and, if it returns
+    /// `CallbackReturn::Continue`, propagated to its parent until the dialog is reached.
+    ///
+    /// `CallbackReturn::Ignore` prevents the key from being processed by the element,
+    /// `CallbackReturn::Close` exits the main loop.
+    ///
+    /// [Learn more](http://webserver2.tecgraf.puc-rio.br/iup/en/call/iup_k_any.html).
+    pub trait KAnyCb where Self: Element {
+        let name = "K_ANY";
+        extern fn listener(ih: *mut iup_sys::Ihandle, c: c_int) -> CallbackReturn;
+        fn set_k_any<F: Callback(Self, Key)>(&mut self, cb: F) -> Self;
+        fn remove_k_any(&mut self) -> Option<Box<_>>;
+    }
+}
+
+/// The key actions of an element, indexed by key code.
+type KeyActions<E> = HashMap<i32, Box<dyn Callback<(E,)>>>;
+
+/// Gets the IUP listener of the key action for the specified key code.
+///
+/// IUP does not pass the key to the `K_*` callbacks, thus each code needs its own listener.
+macro_rules! key_action_listener {
+    ($elem:ty, $code:expr; $($c:expr),*) => {
+        match $code {
+            $( $c => key_action_listener::<$elem, $c> as iup_sys::Icallback, )*
+            _ => unreachable!(),
+        }
+    }
+}
+
+extern "C" fn key_action_listener<E: KeyActionCb, const CODE: i32>(ih: *mut iup_sys::Ihandle) -> c_int {
+    let actions = unsafe { iup_sys::IupGetAttribute(ih, fbox_c_str!("KEY_ACTIONS")) }
+                                                                    as *mut Box<dyn Any>;
+    let element = unsafe { E::from_raw_unchecked(ih) };
+    match unsafe { actions.as_mut() }.and_then(|a| a.downcast_mut::<KeyActions<E>>())
+                                     .and_then(|a| a.get_mut(&CODE)) {
+        Some(cb) => cb.on_callback((element,)),
+        None => iup_sys::IUP_DEFAULT,
+    }
+}
+
+/// Action generated when a specific key is pressed, such as a keyboard shortcut.
+///
+/// The action is called on the element with the keyboard focus and, if it returns
+/// `CallbackReturn::Continue`, propagated to its parent until the dialog is reached, thus
+/// shortcuts for the whole window can be set in the dialog. It is called after `KAnyCb`, and
+/// only when it returns `CallbackReturn::Default`.
+///
+/// Any number of keys may have an action, each key has at most one.
+///
+/// All the key actions of an element must be set through the same Rust type, e.g. actions set
+/// through a `Handle` are not seen by the `Button` it wraps.
+pub trait KeyActionCb where Self: Element + 'static {
+    /// Sets the action for the specified key, replacing any previous action of the key.
+    ///
+    /// # Panics
+    /// Panics if the key has no IUP key code, see `Key::callback_name`.
+    fn set_key_action<F>(&mut self, key: Key, cb: F) -> Self
+                                            where F: Callback<(Self,)> {
+        let name = key.callback_name().expect("the key has no IUP key code");
+        let code = key.to_raw();
+        let listener = key_action_listener!(Self, code;
+            0x08, 0x09, 0x0A, 0x0D,
+            0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F,
+            0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3A, 0x3B, 0x3C, 0x3D, 0x3E, 0x3F,
+            0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F,
+            0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x5B, 0x5C, 0x5D, 0x5E, 0x5F,
+            0x60, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F,
+            0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x7B, 0x7C, 0x7D, 0x7E,
+            0xFF0B, 0xFF13, 0xFF14, 0xFF1B, 0xFF50, 0xFF51, 0xFF52, 0xFF53, 0xFF54, 0xFF55, 0xFF56,
+            0xFF57, 0xFF61, 0xFF63, 0xFF67, 0xFF7F, 0xFFFF,
+            0xFFBE, 0xFFBF, 0xFFC0, 0xFFC1, 0xFFC2, 0xFFC3, 0xFFC4, 0xFFC5, 0xFFC6, 0xFFC7, 0xFFC8, 0xFFC9,
+            0xFFE1, 0xFFE2, 0xFFE3, 0xFFE4, 0xFFE5, 0xFFE9, 0xFFEA
+        );
+
+        unsafe {
+            let mut actions = iup_sys::IupGetAttribute(self.raw(), fbox_c_str!("KEY_ACTIONS"))
+                                                                            as *mut Box<dyn Any>;
+            if actions.is_null() {
+                let table: Box<Box<dyn Any>> = Box::new(Box::new(KeyActions::<Self>::new()));
+                actions = Box::into_raw(table);
+                iup_sys::IupSetAttribute(self.raw(), fbox_c_str!("KEY_ACTIONS"), actions as *const _);
+            }
+            if let Some(actions) = (*actions).downcast_mut::<KeyActions<Self>>() {
+                actions.insert(code, Box::new(cb));
+            }
+
+            let cname = CString::new(name).unwrap();
+            iup_sys::IupSetCallback(self.raw(), cname.as_ptr(), listener);
+        }
+        *self
+    }
+
+    /// Removes the action of the specified key, returning it.
+    fn remove_key_action(&mut self, key: Key) -> Option<Box<dyn Callback<(Self,)>>> {
+        let name = key.callback_name()?;
+        unsafe {
+            let actions = iup_sys::IupGetAttribute(self.raw(), fbox_c_str!("KEY_ACTIONS"))
+                                                                            as *mut Box<dyn Any>;
+            let old_cb = actions.as_mut().and_then(|a| a.downcast_mut::<KeyActions<Self>>())
+                                         .and_then(|a| a.remove(&key.to_raw()));
+            if old_cb.is_some() {
+                let cname = CString::new(name).unwrap();
+                iup_sys::IupSetCallback(self.raw(), cname.as_ptr(),
+                                        mem::transmute::<*const (), iup_sys::Icallback>(ptr::null()));
+            }
+            old_cb
+        }
+    }
+}
@@ -1,16 +1,28 @@
 //! Event-driven communication.
+//!
+//! Each callback trait provides a `set_*` method to associate a closure with the callback and a
+//! `remove_*` method to disassociate it, which returns the previously set closure.
+//!
+//! # Closure Storage
+//!
+//! Closures are boxed and stored in a `_IUPRUST_FBOX_<CALLBACK NAME>` attribute of the element,
+//! setting a new closure or removing it frees the previous one. Any closure still associated
+//! with an element is freed when the element is destroyed, through `drop_callbacks`.
 
 use iup_sys;
-use libc::{c_char, c_int};
+use libc::{c_char, c_int, c_float};
 use std::path::PathBuf;
 use std::char;
 
+use {Element, Handle};
+
 #[macro_use]
 mod macros;
 pub mod callbacks;
 pub use self::callbacks::*;
 
 pub mod button;
+pub mod key;
 
 // This is called right when a IUP element is being destroyed and it should free up all data
 // associated with callbacks. Just use the `drop_callback!` macro for each callback implemented.
@@ -21,6 +33,11 @@ pub unsafe fn drop_callbacks(ih: *mut iup_sys::Ihandle) {
     // button.rs
     drop_callback!(ih, "BUTTON_CB");
     drop_callback!(ih, "MOTION_CB");
+    drop_callback!(ih, "WHEEL_CB");
+
+    // key.rs
+    drop_callback!(ih, "K_ANY");
+    drop_callback!(ih, "KEY_ACTIONS");
 
     // callbacks.rs
     drop_callback!(ih, "ACTION");
@@ -40,6 +57,22 @@ pub unsafe fn drop_callbacks(ih: *mut iup_sys::Ihandle) {
     drop_callback!(ih, "CLOSE_CB");
     drop_callback!(ih, "MOVE_CB");
     drop_callback!(ih, "RESIZE_CB");
+    drop_callback!(ih, "SCROLL_CB");
+
+    // list.rs
+    drop_callback!(ih, "DBLCLICK_CB");
+
+    // tabs.rs
+    drop_callback!(ih, "TABCHANGE_CB");
+
+    // tree.rs
+    drop_callback!(ih, "SELECTION_CB");
+
+    // matrix.rs
+    drop_callback!(ih, "CLICK_CB");
+
+    // expander.rs
+    drop_callback!(ih, "OPENCLOSE_CB");
 
     // dialog.rs
     drop_callback!(ih, "COPYDATA_CB");
@@ -58,8 +91,8 @@ pub unsafe fn drop_callbacks(ih: *mut iup_sys::Ihandle) {
 pub enum CallbackReturn {
     /// The default `CallbackReturn`, does nothing when returned.
     Default,
-    /// If this is returned from a callback, then when the callback returns the dialog containing
-    /// the element on which the callback was invoked will be closed.
+    /// If this is returned from a callback, then when the callback returns the main loop is
+    /// exited, just like calling `iup::exit_loop`, and `with_iup` returns.
     Close,
     /// Callback specific, check the callback documentation to see if it accepts this return value
     /// and it's effect.
@@ -134,12 +167,24 @@ impl IntoRust<usize> for c_int {
     }
 }
 
+impl IntoRust<f32> for c_float {
+    fn into_rust(self) -> f32 {
+        self
+    }
+}
+
 impl IntoRust<bool> for c_int {
     fn into_rust(self) -> bool {
         self != 0
     }
 }
 
+impl IntoRust<Handle> for *mut iup_sys::Ihandle {
+    fn into_rust(self) -> Handle {
+        Handle::from_raw(self)
+    }
+}
+
 impl IntoRust<PathBuf> for *const c_char {
     fn into_rust(self) -> PathBuf {
         PathBuf::from(string_from_cstr!(self))
@@ -82,13 +82,20 @@
 //! ```
 //!
 use iup_sys;
+use libc::c_void;
 use std::ptr;
 use std::slice;
 
 use Element;
+use Handle;
 use Guard;
 use image::ImageElement;
 
+// Not part of the iup-sys bindings.
+extern "C" {
+    fn IupGetNativeHandleImage(handle: *mut c_void) -> *mut iup_sys::Ihandle;
+}
+
 /// An element that allows access to the clipboard.
 ///
 /// You can use only one for the entire application because it does not store any data inside.
@@ -146,11 +153,24 @@ impl Clipboard {
         self.attrib_bool("IMAGEAVAILABLE").unwrap()
     }
 
-    /// Copy text into the clipboard.
+    /// Copy an image into the clipboard.
     pub fn set_image<I: ImageElement>(&mut self, image: &I) -> Self {
         self.set_attrib_handle("IMAGE", *image)
     }
 
+    /// Paste the image from the clipboard, if any.
+    ///
+    /// The new image element is not associated with a handle name, thus it's guarded.
+    pub fn image(&mut self) -> Option<Guard<Handle>> {
+        match self.attrib_data("NATIVEIMAGE") {
+            native if native.is_null() => None,
+            native => match unsafe { IupGetNativeHandleImage(native) } {
+                ih if ih.is_null() => None,
+                ih => Some(Guard::new(Handle::from_raw(ih))),
+            },
+        }
+    }
+
 
     /// Informs if there is data of the specified format available at the clipboard.
     pub fn has_data<S: Into<String>>(&mut self, format: S) -> bool {
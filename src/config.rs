@@ -0,0 +1,179 @@
+//! Persistent application settings stored in INI-like files.
+//!
+//! The configuration file contains groups (sections) of variables in the form:
+//!
+//! ```text
+//! [Group]
+//! Key=Value
+//! ```
+//!
+//! # Example
+//! ```ignore
+//! let mut config = Config::load("myapp.cfg").unwrap_or_else(|_| Config::new());
+//! let count = config.variable_int("Main", "RunCount", 0);
+//! config.set_variable_int("Main", "RunCount", count + 1);
+//! config.save().unwrap();
+//! ```
+use iup_sys;
+use libc::{c_char, c_int, c_double};
+use std::path::Path;
+use std::ffi::CString;
+
+use Element;
+use Guard;
+use dialog::Dialog;
+
+// The configuration functions are not part of the iup-sys bindings.
+extern "C" {
+    fn IupConfig() -> *mut iup_sys::Ihandle;
+    fn IupConfigLoad(ih: *mut iup_sys::Ihandle) -> c_int;
+    fn IupConfigSave(ih: *mut iup_sys::Ihandle) -> c_int;
+    fn IupConfigSetVariableStr(ih: *mut iup_sys::Ihandle, group: *const c_char,
+                               key: *const c_char, value: *const c_char);
+    fn IupConfigSetVariableInt(ih: *mut iup_sys::Ihandle, group: *const c_char,
+                               key: *const c_char, value: c_int);
+    fn IupConfigSetVariableDouble(ih: *mut iup_sys::Ihandle, group: *const c_char,
+                                  key: *const c_char, value: c_double);
+    fn IupConfigGetVariableStr(ih: *mut iup_sys::Ihandle, group: *const c_char,
+                               key: *const c_char) -> *const c_char;
+    fn IupConfigGetVariableIntDef(ih: *mut iup_sys::Ihandle, group: *const c_char,
+                                  key: *const c_char, def: c_int) -> c_int;
+    fn IupConfigGetVariableDoubleDef(ih: *mut iup_sys::Ihandle, group: *const c_char,
+                                     key: *const c_char, def: c_double) -> c_double;
+    fn IupConfigDialogShow(ih: *mut iup_sys::Ihandle, dialog: *mut iup_sys::Ihandle,
+                           name: *const c_char);
+    fn IupConfigDialogClosed(ih: *mut iup_sys::Ihandle, dialog: *mut iup_sys::Ihandle,
+                             name: *const c_char);
+}
+
+/// Translates the status code of `IupConfigLoad` and `IupConfigSave`.
+fn config_result(code: c_int) -> Result<(), String> {
+    match code {
+        0 => Ok(()),
+        -1 => Err("config: failed to open the file".into()),
+        -2 => Err("config: failed to access the file".into()),
+        -3 => Err("config: invalid file name".into()),
+        _ => Err("config: unknown error".into()),
+    }
+}
+
+/// A group of persistent variables backed by a configuration file.
+///
+/// The variables are kept in memory and are only written to disk on `save`.
+///
+/// # Naming
+///
+/// The methods follow the IUP function names: `set_variable`, `variable`, `set_variable_int`,
+/// `variable_int`, `set_variable_float` and `variable_float` wrap `IupConfigSetVariable*` and
+/// `IupConfigGetVariable*`, while `dialog_show` and `dialog_closed` wrap `IupConfigDialogShow`
+/// and `IupConfigDialogClosed` to store and restore the position and size of a dialog.
+///
+/// # Ownership
+///
+/// The config must be manually destroyed, thus for the user safety it returns a guarded object
+/// on the `new` and `load` constructors.
+///
+/// Please refer to the crate level documentation of IUP-Rust (the main doc page) for details on
+/// ownership of elements.
+pub struct Config(*mut iup_sys::Ihandle);
+
+impl Config {
+    /// Constructs an empty configuration without any associated file.
+    ///
+    /// Use `set_filename` before calling `save`.
+    pub fn new() -> Guard<Config> {
+        Guard::new(
+            Config::from_raw(unsafe { IupConfig() })
+        )
+    }
+
+    /// Constructs a configuration from the variables stored in the specified file.
+    ///
+    /// In case of failure returns the error message. The file does not exist yet on the first
+    /// run of an application, so it's common to fall back to `new` and `set_filename`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Guard<Config>, String> {
+        let mut config = Config::new();
+        config.set_filename(path)?;
+        config_result(unsafe { IupConfigLoad(config.raw()) })?;
+        Ok(config)
+    }
+
+    /// Sets the file used by `save`.
+    pub fn set_filename<P: AsRef<Path>>(&mut self, path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let str = path.to_str().ok_or_else(|| "Failed to convert Path to string".to_string())?;
+        Ok(self.set_attrib("APP_FILENAME", str))
+    }
+
+    /// Writes every variable to the configuration file.
+    ///
+    /// In case of failure returns the error message.
+    pub fn save(&self) -> Result<(), String> {
+        config_result(unsafe { IupConfigSave(self.raw()) })
+    }
+
+    /// Sets the string value of the variable `key` in the `group` section.
+    pub fn set_variable(&mut self, group: &str, key: &str, value: &str) -> Self {
+        let (group, key) = (CString::new(group).unwrap(), CString::new(key).unwrap());
+        let value = CString::new(value).unwrap();
+        unsafe { IupConfigSetVariableStr(self.raw(), group.as_ptr(), key.as_ptr(), value.as_ptr()) };
+        *self
+    }
+
+    /// Gets the string value of the variable `key` in the `group` section or `None` if not set.
+    pub fn variable(&self, group: &str, key: &str) -> Option<String> {
+        let (group, key) = (CString::new(group).unwrap(), CString::new(key).unwrap());
+        match unsafe { IupConfigGetVariableStr(self.raw(), group.as_ptr(), key.as_ptr()) } {
+            value if value.is_null() => None,
+            value => Some(string_from_cstr!(value)),
+        }
+    }
+
+    /// Sets the integer value of the variable `key` in the `group` section.
+    pub fn set_variable_int(&mut self, group: &str, key: &str, value: i32) -> Self {
+        let (group, key) = (CString::new(group).unwrap(), CString::new(key).unwrap());
+        unsafe { IupConfigSetVariableInt(self.raw(), group.as_ptr(), key.as_ptr(), value) };
+        *self
+    }
+
+    /// Gets the integer value of the variable `key` in the `group` section or `default` if
+    /// not set.
+    pub fn variable_int(&self, group: &str, key: &str, default: i32) -> i32 {
+        let (group, key) = (CString::new(group).unwrap(), CString::new(key).unwrap());
+        unsafe { IupConfigGetVariableIntDef(self.raw(), group.as_ptr(), key.as_ptr(), default) }
+    }
+
+    /// Sets the floating point value of the variable `key` in the `group` section.
+    pub fn set_variable_float(&mut self, group: &str, key: &str, value: f64) -> Self {
+        let (group, key) = (CString::new(group).unwrap(), CString::new(key).unwrap());
+        unsafe { IupConfigSetVariableDouble(self.raw(), group.as_ptr(), key.as_ptr(), value) };
+        *self
+    }
+
+    /// Gets the floating point value of the variable `key` in the `group` section or `default`
+    /// if not set.
+    pub fn variable_float(&self, group: &str, key: &str, default: f64) -> f64 {
+        let (group, key) = (CString::new(group).unwrap(), CString::new(key).unwrap());
+        unsafe { IupConfigGetVariableDoubleDef(self.raw(), group.as_ptr(), key.as_ptr(), default) }
+    }
+
+    /// Shows the dialog restoring the position, size and maximized state stored under `name`.
+    ///
+    /// The dialog is shown centered the first time, when there's nothing yet stored.
+    pub fn dialog_show(&mut self, dialog: &Dialog, name: &str) -> Self {
+        let name = CString::new(name).unwrap();
+        unsafe { IupConfigDialogShow(self.raw(), dialog.raw(), name.as_ptr()) };
+        *self
+    }
+
+    /// Stores the position, size and maximized state of the dialog under `name`.
+    ///
+    /// Should be called when the dialog gets closed, usually from its `CloseCb`.
+    pub fn dialog_closed(&mut self, dialog: &Dialog, name: &str) -> Self {
+        let name = CString::new(name).unwrap();
+        unsafe { IupConfigDialogClosed(self.raw(), dialog.raw(), name.as_ptr()) };
+        *self
+    }
+}
+
+impl_element!(Config, "user");
@@ -28,10 +28,15 @@ impl ::callback::MapCb for Button {}
 impl ::callback::UnmapCb for Button {}
 impl ::callback::GetFocusCb for Button {}
 impl ::callback::KillFocusCb for Button {}
+impl ::callback::key::KAnyCb for Button {}
+impl ::callback::key::KeyActionCb for Button {}
 impl ::callback::EnterWindowCb for Button {}
 impl ::callback::LeaveWindowCb for Button {}
 impl ::callback::HelpCb for Button {}
-// TODO impl K_ callbacks when it's implemented.
+impl ::attribute::TitleAttribute for Button {}
+impl ::attribute::BgColorAttribute for Button {}
+impl ::attribute::FgColorAttribute for Button {}
+impl ::attribute::TipAttribute for Button {}
 
 /// Action generated when the button 1 (usually left) is selected.
 ///
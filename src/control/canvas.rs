@@ -0,0 +1,109 @@
+use iup_sys;
+use libc::c_float;
+use std::ptr;
+
+use Element;
+
+/// A working area for the application to draw on.
+///
+/// Actual drawing must be done by a graphics library such as CD or OpenGL using the native
+/// handle of the canvas. The `CanvasAction` callback notifies when the canvas must be redrawn.
+///
+/// See the [IUP Canvas Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupcanvas.html
+pub struct Canvas(*mut iup_sys::Ihandle);
+
+impl Canvas {
+    /// Creates a canvas.
+    pub fn new() -> Canvas {
+        unsafe { Canvas::from_raw(iup_sys::IupCanvas(ptr::null_mut())) }
+    }
+
+    /// Gets the size of the drawing area in pixels as a (width, height) pair.
+    ///
+    /// This size is also used in the `ResizeCb` callback.
+    pub fn draw_size(&self) -> (u32, u32) {
+        self.attrib_int_pair("DRAWSIZE").map(|(w, h)| (w as u32, h as u32)).unwrap_or((0, 0))
+    }
+
+    /// Sets whether the horizontal scrollbar is hidden when the visible area is larger than
+    /// the scrollable area (the default) or just disabled.
+    pub fn set_xautohide(&mut self, autohide: bool) -> Self {
+        self.set_attrib("XAUTOHIDE", if autohide { "YES" } else { "NO" })
+    }
+
+    /// Sets whether the vertical scrollbar is hidden when the visible area is larger than
+    /// the scrollable area (the default) or just disabled.
+    pub fn set_yautohide(&mut self, autohide: bool) -> Self {
+        self.set_attrib("YAUTOHIDE", if autohide { "YES" } else { "NO" })
+    }
+}
+
+impl Default for Canvas {
+    fn default() -> Canvas {
+        Canvas::new()
+    }
+}
+
+impl_widget!(Canvas, "canvas");
+
+impl ::callback::MapCb for Canvas {}
+impl ::callback::UnmapCb for Canvas {}
+impl ::callback::GetFocusCb for Canvas {}
+impl ::callback::KillFocusCb for Canvas {}
+impl ::callback::key::KAnyCb for Canvas {}
+impl ::callback::key::KeyActionCb for Canvas {}
+impl ::callback::EnterWindowCb for Canvas {}
+impl ::callback::LeaveWindowCb for Canvas {}
+impl ::callback::HelpCb for Canvas {}
+impl ::attribute::BgColorAttribute for Canvas {}
+impl ::attribute::ScrollBarAttribute for Canvas {}
+impl ::attribute::CursorAttribute for Canvas {}
+impl ::attribute::TipAttribute for Canvas {}
+impl ::attribute::DropTargetAttribute for Canvas {}
+
+/// Action generated when the canvas size is changed.
+///
+/// The `i32` parameters are the width and height of the drawing area (DRAWSIZE), in pixels.
+///
+/// This action is also generated when the canvas is mapped.
+impl ::callback::ResizeCb for Canvas {}
+
+/// Called when some manipulation is made to the scrollbars of the canvas.
+impl ::callback::ScrollCb for Canvas {}
+
+/// Action generated when one or more files are dropped in the canvas.
+impl ::callback::DropFilesCb for Canvas {}
+
+/// Action generated when any mouse button is pressed or released inside the canvas.
+///
+/// The `i32` parameters are the x,y position in the canvas where the event has occurred,
+/// in pixels.
+impl ::callback::button::ButtonCb for Canvas {}
+
+/// Action generated when the mouse is moved over the canvas.
+impl ::callback::button::MotionCb for Canvas {}
+
+/// Action generated when the mouse wheel is rotated over the canvas.
+///
+/// If this callback is not defined the wheel will automatically scroll the canvas in the
+/// vertical direction.
+impl ::callback::button::WheelCb for Canvas {}
+
+/// See the `CanvasAction` documentation.
+impl self::CanvasAction for Canvas {}
+
+impl_callback! {
+    #[doc="Action generated when the canvas needs to be redrawn."]
+    #[doc=""]
+    #[doc="The `f32` parameters are the thumb position of the horizontal and vertical scrollbars"]
+    #[doc="(POSX and POSY attributes)."]
+    #[doc=""]
+    #[doc="`CallbackReturn::Close` will be processed."]
+    pub trait CanvasAction where Self: Element {
+        let name = "ACTION";
+        extern fn listener(ih: *mut iup_sys::Ihandle, posx: c_float, posy: c_float) -> CallbackReturn;
+        fn set_action<F: Callback(Self, f32, f32)>(&mut self, cb: F) -> Self;
+        fn remove_action(&mut self) -> Option<Box<_>>;
+    }
+}
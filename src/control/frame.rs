@@ -17,8 +17,29 @@ impl Frame {
     pub fn new_empty() -> Frame {
         unsafe { Frame::from_raw(iup_sys::IupFrame(ptr::null_mut())) }
     }
+
+    /// Creates a frame with a title and a child element.
+    pub fn with_title<S: Into<String>, E: Element>(title: S, child: E) -> Frame {
+        Frame::new(child).set_attrib("TITLE", title)
+    }
+
+    /// Sets whether the frame border is sunken, only used when the frame has no title.
+    ///
+    /// This is the SUNKEN attribute and can only be set before the frame is mapped.
+    pub fn set_sunken(&mut self, sunken: bool) -> Self {
+        self.set_attrib("SUNKEN", if sunken { "YES" } else { "NO" })
+    }
+
+    /// Checks whether the frame border is sunken.
+    pub fn is_sunken(&self) -> bool {
+        self.attrib_bool("SUNKEN").unwrap_or(false)
+    }
 }
 
 impl_widget_container!(Frame, "frame");
 impl ::callback::MapCb for Frame {}
 impl ::callback::UnmapCb for Frame {}
+impl ::attribute::TitleAttribute for Frame {}
+impl ::attribute::BgColorAttribute for Frame {}
+impl ::attribute::FgColorAttribute for Frame {}
+impl ::attribute::TipAttribute for Frame {}
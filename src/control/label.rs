@@ -34,6 +34,11 @@ impl ::callback::MapCb for Label {}
 impl ::callback::UnmapCb for Label {}
 impl ::callback::EnterWindowCb for Label {}
 impl ::callback::LeaveWindowCb for Label {}
+impl ::attribute::TitleAttribute for Label {}
+impl ::attribute::BgColorAttribute for Label {}
+impl ::attribute::FgColorAttribute for Label {}
+impl ::attribute::TipAttribute for Label {}
+impl ::attribute::DropTargetAttribute for Label {}
 
 /// Action generated when any mouse button is pressed or released.
 impl ::callback::button::ButtonCb for Label {}
@@ -31,7 +31,7 @@ impl List {
     pub fn set_items<A>(&mut self, items: A) -> Self where A: AsRef<[String]> {
         self.clear();
         for (i, value) in items.as_ref().iter().enumerate() {
-            self.set_attrib((i+1).to_string(), value.clone());
+            self.set_attrib_id("", (i+1) as i32, value.clone());
         }
         *self
     }
@@ -40,9 +40,61 @@ impl List {
     ///
     /// # Panics
     /// Panics if id is less than 1.
-    pub fn item<A>(&self, id: u32) -> Option<String> {
+    pub fn item(&self, id: u32) -> Option<String> {
         assert!(id > 0);
-        self.attrib(id.to_string())
+        self.attrib_id("", id as i32)
+    }
+
+    /// Adds an item after the last item. Ignored if called before being mapped.
+    pub fn append_item<S: Into<String>>(&mut self, item: S) -> Self {
+        self.set_attrib("APPENDITEM", item)
+    }
+
+    /// Inserts an item before the item at the specified id (starts from 1).
+    ///
+    /// If id is *count+1* the item is appended after the last item.
+    /// Ignored if called before being mapped or if the id is out of range.
+    ///
+    /// # Panics
+    /// Panics if id is less than 1.
+    pub fn insert_item<S: Into<String>>(&mut self, id: u32, item: S) -> Self {
+        assert!(id > 0);
+        self.set_attrib_id("INSERTITEM", id as i32, item)
+    }
+
+    /// Removes the item at the specified id (starts from 1).
+    ///
+    /// Ignored if called before being mapped or if the id is out of range.
+    ///
+    /// # Panics
+    /// Panics if id is less than 1.
+    pub fn remove_item(&mut self, id: u32) -> Self {
+        assert!(id > 0);
+        self.set_attrib("REMOVEITEM", id.to_string())
+    }
+
+    /// Gets the number of items in the list.
+    pub fn item_count(&self) -> u32 {
+        self.attrib_parse("COUNT").unwrap_or(0)
+    }
+
+    /// Selects the item at the specified id (starts from 1).
+    ///
+    /// Only valid for single selection lists without an edit box, on those the VALUE attribute
+    /// is the text in the edit box instead.
+    ///
+    /// # Panics
+    /// Panics if id is less than 1.
+    pub fn set_value(&mut self, id: u32) -> Self {
+        assert!(id > 0);
+        self.set_attrib("VALUE", id.to_string())
+    }
+
+    /// Gets the id (starts from 1) of the selected item or `None` if there's no selection.
+    ///
+    /// Only valid for single selection lists without an edit box.
+    pub fn value(&self) -> Option<u32> {
+        self.attrib_parse("VALUE").and_then(|id| if id == 0 { None } else { Some(id) })
     }
 
     /// Clears the list of items. Ignored if called before being mapped.
@@ -60,10 +112,15 @@ impl ::callback::MapCb for List {}
 impl ::callback::UnmapCb for List {}
 impl ::callback::GetFocusCb for List {}
 impl ::callback::KillFocusCb for List {}
+impl ::callback::key::KAnyCb for List {}
+impl ::callback::key::KeyActionCb for List {}
 impl ::callback::EnterWindowCb for List {}
 impl ::callback::LeaveWindowCb for List {}
 impl ::callback::HelpCb for List {}
-// TODO impl K_ callbacks when it's implemented.
+impl ::attribute::BgColorAttribute for List {}
+impl ::attribute::FgColorAttribute for List {}
+impl ::attribute::TipAttribute for List {}
+impl ::attribute::DropTargetAttribute for List {}
 
 // TODO impl future DragSource and DragTarget traits.
 
@@ -95,8 +152,10 @@ impl ::callback::button::MotionCb for List {}
 /// changed or when the text is edited.
 impl ::callback::ValueChangedCb for List {}
 
+/// See the `ListDblClickCb` documentation.
+impl self::ListDblClickCb for List {}
+
 // TODO:
-// DBLCLICK_CB
 // MULTISELECT_CB
 // EDIT_CB
 // DROPDOWN_CB
@@ -124,6 +183,19 @@ impl_callback! {
     }
 }
 
+impl_callback! {
+    #[doc="Action generated when the user double click an item. Called only when DROPDOWN=NO."]
+    #[doc=""]
+    #[doc="The `u32` parameter is the number of the clicked item starting at 1."]
+    #[doc="The `String` parameter is the text of the clicked item."]
+    pub trait ListDblClickCb where Self: Element {
+        let name = "DBLCLICK_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, item: c_int, text: *const c_char) -> CallbackReturn;
+        fn set_dblclick_cb<F: Callback(Self, u32, String)>(&mut self, cb: F) -> Self;
+        fn remove_dblclick_cb(&mut self) -> Option<Box<_>>;
+    }
+}
+
 pub enum ListItemState {
     Deselected,
     Selected,
@@ -0,0 +1,121 @@
+use iup_sys;
+use libc::c_char;
+
+use Element;
+use callback::button::KeyStates;
+
+/// A spreadsheet-like control that displays values in a grid of cells.
+///
+/// Cells are identified by a line and a column starting at 1. The line 0 holds the column
+/// titles and the column 0 holds the line titles.
+///
+/// # Initialization
+///
+/// The matrix is part of the IUP additional controls library (`iupcontrols`), which must be
+/// linked and initialized with `IupControlsOpen` before the first matrix is created.
+///
+/// See the [IUP Matrix Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/ctrl/iupmatrix.html
+pub struct Matrix(*mut iup_sys::Ihandle);
+
+impl Matrix {
+    /// Creates a matrix with no lines and no columns.
+    ///
+    /// # Panics
+    /// Panics if the `iupcontrols` library has not been initialized.
+    pub fn new() -> Matrix {
+        match unsafe { iup_sys::IupCreate(cstr!("matrix")) } {
+            ih if ih.is_null() => panic!("IupMatrix is not available, call IupControlsOpen first"),
+            ih => Matrix::from_raw(ih),
+        }
+    }
+
+    /// Sets the number of columns, not including the title column.
+    pub fn set_num_col(&mut self, n: usize) -> Self {
+        self.set_attrib("NUMCOL", n.to_string())
+    }
+
+    /// Gets the number of columns, not including the title column.
+    pub fn num_col(&self) -> usize {
+        self.attrib_parse("NUMCOL").unwrap_or(0)
+    }
+
+    /// Sets the number of lines, not including the title line.
+    pub fn set_num_lin(&mut self, n: usize) -> Self {
+        self.set_attrib("NUMLIN", n.to_string())
+    }
+
+    /// Gets the number of lines, not including the title line.
+    pub fn num_lin(&self) -> usize {
+        self.attrib_parse("NUMLIN").unwrap_or(0)
+    }
+
+    /// Sets the text of the cell at the specified line and column.
+    ///
+    /// The matrix is not redrawn automatically, set the REDRAW attribute or call
+    /// `Node::update` after changing many cells.
+    pub fn set_cell<S: Into<String>>(&mut self, lin: usize, col: usize, value: S) -> Self {
+        self.set_attrib_id2("", lin as i32, col as i32, value)
+    }
+
+    /// Gets the text of the cell at the specified line and column.
+    pub fn cell(&self, lin: usize, col: usize) -> Option<String> {
+        self.attrib_id2("", lin as i32, col as i32)
+    }
+
+    /// Sets the title of the specified column (starting at 1).
+    pub fn set_col_title<S: Into<String>>(&mut self, col: usize, title: S) -> Self {
+        self.set_cell(0, col, title)
+    }
+
+    /// Sets the title of the specified line (starting at 1).
+    pub fn set_lin_title<S: Into<String>>(&mut self, lin: usize, title: S) -> Self {
+        self.set_cell(lin, 0, title)
+    }
+}
+
+impl Default for Matrix {
+    fn default() -> Matrix {
+        Matrix::new()
+    }
+}
+
+impl_widget!(Matrix, "matrix");
+
+impl ::callback::MapCb for Matrix {}
+impl ::callback::UnmapCb for Matrix {}
+impl ::callback::GetFocusCb for Matrix {}
+impl ::callback::KillFocusCb for Matrix {}
+impl ::callback::key::KAnyCb for Matrix {}
+impl ::callback::key::KeyActionCb for Matrix {}
+impl ::callback::EnterWindowCb for Matrix {}
+impl ::callback::LeaveWindowCb for Matrix {}
+impl ::callback::HelpCb for Matrix {}
+impl ::attribute::BgColorAttribute for Matrix {}
+impl ::attribute::FgColorAttribute for Matrix {}
+impl ::attribute::TipAttribute for Matrix {}
+
+/// See the `MatrixClickCb` documentation.
+impl self::MatrixClickCb for Matrix {}
+
+// TODO:
+// ENTERITEM_CB
+// LEAVEITEM_CB
+// EDITION_CB
+// VALUE_CB / VALUE_EDIT_CB (callback mode)
+
+impl_callback! {
+    #[doc="Action generated when any mouse button is pressed over a cell."]
+    #[doc=""]
+    #[doc="The `usize` parameters are the line and column of the clicked cell, the title line and"]
+    #[doc="column are 0."]
+    #[doc=""]
+    #[doc="The `KeyStates` parameter is the state of the mouse buttons and some keyboard keys at"]
+    #[doc="the moment the event is generated."]
+    pub trait MatrixClickCb where Self: Element {
+        let name = "CLICK_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, lin: c_int, col: c_int, status: *mut c_char) -> CallbackReturn;
+        fn set_click_cb<F: Callback(Self, usize, usize, KeyStates)>(&mut self, cb: F) -> Self;
+        fn remove_click_cb(&mut self) -> Option<Box<_>>;
+    }
+}
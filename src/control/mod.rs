@@ -3,16 +3,28 @@
 
 pub mod label;
 pub mod text;
+pub mod multiline;
 pub mod button;
 pub mod progress;
 pub mod toggle;
 pub mod frame;
 pub mod list;
+pub mod canvas;
+pub mod val;
+pub mod tabs;
+pub mod tree;
+pub mod matrix;
 
-pub use self::text::{Text, TextAction};
+pub use self::text::{Text, TextElement, TextAction};
+pub use self::multiline::MultiLine;
 pub use self::label::Label;
 pub use self::button::Button;
 pub use self::progress::ProgressBar;
-pub use self::toggle::{Toggle, ToggleAction};
+pub use self::toggle::{Toggle, ToggleState, ToggleAction};
 pub use self::frame::Frame;
-pub use self::list::{List, ListAction};
+pub use self::list::{List, ListAction, ListDblClickCb};
+pub use self::canvas::{Canvas, CanvasAction};
+pub use self::val::{Val, ValValueChangedCb};
+pub use self::tabs::{Tabs, TabChangeCb};
+pub use self::tree::{Tree, TreeSelectionCb};
+pub use self::matrix::{Matrix, MatrixClickCb};
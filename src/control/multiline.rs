@@ -0,0 +1,112 @@
+use iup_sys;
+use std::ptr;
+
+use Element;
+use control::TextElement;
+
+/// A editable multi-line text control.
+///
+/// This is a `Text` with MULTILINE=YES, most of its functionality is in the `TextElement` trait.
+///
+/// See the [IUP Text Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptext.html
+pub struct MultiLine(*mut iup_sys::Ihandle);
+
+impl MultiLine {
+    /// Creates a editable multi-line text control.
+    pub fn new() -> MultiLine {
+        unsafe { MultiLine::from_raw(iup_sys::IupMultiLine(ptr::null_mut())) }
+    }
+
+    /// Gets the number of lines in the text.
+    pub fn line_count(&self) -> usize {
+        self.attrib_parse("LINECOUNT").unwrap_or(1)
+    }
+
+    /// Gets the text at the specified line (starts from 1) without the line break.
+    ///
+    /// Returns `None` if the line does not exist. Lines are counted as in `line_count`, thus a
+    /// text ending with a line break has an empty last line.
+    pub fn line_value(&self, lin: usize) -> Option<String> {
+        if lin == 0 {
+            return None;
+        }
+        self.value().split('\n').nth(lin - 1).map(|line| line.to_string())
+    }
+
+    /// Gets the caret position as a (lin, col) pair, both start from 1.
+    pub fn caret_lin_col(&self) -> (usize, usize) {
+        self.attrib_int_pair("CARET").map(|(l, c)| (l as usize, c as usize)).unwrap_or((1, 1))
+    }
+
+    /// Sets the caret position at the specified (lin, col) pair, both start from 1.
+    pub fn set_caret_lin_col(&mut self, lin: usize, col: usize) -> Self {
+        self.set_attrib("CARET", format!("{},{}", lin, col))
+    }
+
+    /// Appends text to the end of the current text.
+    ///
+    /// A line break is inserted before the new text unless APPENDNEWLINE=NO.
+    pub fn append<S: Into<String>>(&mut self, text: S) -> Self {
+        self.set_attrib("APPEND", text)
+    }
+
+    /// Sets whether lines are automatically broken when they reach the end of the control.
+    ///
+    /// This is the WORDWRAP attribute and can only be set before the control is mapped.
+    /// When enabled the horizontal scrollbar is removed.
+    pub fn set_wordwrap(&mut self, wordwrap: bool) -> Self {
+        self.set_attrib("WORDWRAP", if wordwrap { "YES" } else { "NO" })
+    }
+}
+
+impl Default for MultiLine {
+    fn default() -> MultiLine {
+        MultiLine::new()
+    }
+}
+
+impl_widget!(MultiLine, "multiline");
+
+/// See the `TextElement` documentation.
+impl ::control::TextElement for MultiLine {}
+
+/// Returns a position in the string.
+impl ::element::ConvertXYToPos for MultiLine {}
+
+impl ::callback::MapCb for MultiLine {}
+impl ::callback::UnmapCb for MultiLine {}
+impl ::callback::GetFocusCb for MultiLine {}
+impl ::callback::KillFocusCb for MultiLine {}
+impl ::callback::key::KAnyCb for MultiLine {}
+impl ::callback::key::KeyActionCb for MultiLine {}
+impl ::callback::EnterWindowCb for MultiLine {}
+impl ::callback::LeaveWindowCb for MultiLine {}
+impl ::callback::HelpCb for MultiLine {}
+impl ::attribute::BgColorAttribute for MultiLine {}
+impl ::attribute::FgColorAttribute for MultiLine {}
+impl ::attribute::ScrollBarAttribute for MultiLine {}
+impl ::attribute::TipAttribute for MultiLine {}
+impl ::attribute::DropTargetAttribute for MultiLine {}
+
+/// Action generated when any mouse button is pressed or released.
+///
+/// Use `convert_xy_to_pos` to convert (x,y) coordinates in character positioning.
+impl ::callback::button::ButtonCb for MultiLine {}
+
+/// Action generated when the caret/cursor position is changed.
+impl ::callback::CaretCb for MultiLine {}
+
+/// Action generated when one or more files are dropped in the element.
+impl ::callback::DropFilesCb for MultiLine {}
+
+/// Action generated when the mouse is moved.
+///
+/// Use `convert_xy_to_pos` to convert (x,y) coordinates in character positioning.
+impl ::callback::button::MotionCb for MultiLine {}
+
+/// Called after the value was interactively changed by the user.
+impl ::callback::ValueChangedCb for MultiLine {}
+
+/// See the `TextAction` documentation.
+impl ::control::TextAction for MultiLine {}
@@ -1,6 +1,7 @@
 use iup_sys;
 
 use Element;
+use Orientation;
 
 /// See the [IUP Progress Bar Documentation][1].
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupprogressbar.html
@@ -11,8 +12,58 @@ impl ProgressBar {
     pub fn new() -> ProgressBar {
         unsafe { ProgressBar::from_raw(iup_sys::IupProgressBar()) } 
     }
+
+    /// Sets the current value of the progress bar.
+    ///
+    /// The value is clamped to the `[min, max]` interval of the progress bar.
+    pub fn set_value(&mut self, value: f64) -> Self {
+        let value = value.max(self.min()).min(self.max());
+        self.set_attrib("VALUE", value.to_string())
+    }
+
+    /// Gets the current value of the progress bar.
+    pub fn value(&self) -> f64 {
+        self.attrib_parse("VALUE").unwrap_or_else(|| self.min())
+    }
+
+    /// Sets the minimum value of the progress bar. Default is 0.
+    pub fn set_min(&mut self, min: f64) -> Self {
+        self.set_attrib("MIN", min.to_string())
+    }
+
+    /// Gets the minimum value of the progress bar.
+    pub fn min(&self) -> f64 {
+        self.attrib_parse("MIN").unwrap_or(0.0)
+    }
+
+    /// Sets the maximum value of the progress bar. Default is 1.
+    pub fn set_max(&mut self, max: f64) -> Self {
+        self.set_attrib("MAX", max.to_string())
+    }
+
+    /// Gets the maximum value of the progress bar.
+    pub fn max(&self) -> f64 {
+        self.attrib_parse("MAX").unwrap_or(1.0)
+    }
+
+    /// Sets whether the progress bar shows an undefined state, the value is then ignored.
+    ///
+    /// Useful to indicate that an action is being performed but its progress is unknown.
+    pub fn set_marquee(&mut self, marquee: bool) -> Self {
+        self.set_attrib("MARQUEE", if marquee { "YES" } else { "NO" })
+    }
+
+    /// Sets the orientation of the progress bar. Default is horizontal.
+    ///
+    /// The size of the progress bar is not swapped by this, use `SizeAttribute` for that.
+    pub fn set_orientation(&mut self, orient: Orientation) -> Self {
+        self.set_attrib_data("ORIENTATION", orient.as_cstr() as *const _)
+    }
 }
 
 impl_widget!(ProgressBar, "progressbar");
 impl ::callback::MapCb for ProgressBar {}
 impl ::callback::UnmapCb for ProgressBar {}
+impl ::attribute::BgColorAttribute for ProgressBar {}
+impl ::attribute::FgColorAttribute for ProgressBar {}
+impl ::attribute::TipAttribute for ProgressBar {}
@@ -0,0 +1,83 @@
+use iup_sys;
+
+use Handle;
+use Element;
+
+/// A container of several children where only one is visible at a time, the user chooses
+/// which one by clicking on its tab.
+///
+/// Tab positions start from 0.
+///
+/// See the [IUP Tabs Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptabs.html
+pub struct Tabs(*mut iup_sys::Ihandle);
+
+impl Tabs {
+    /// Creates a tabs container with the specified childs, one for each tab.
+    ///
+    /// More tabs can be added later with the `Container` methods.
+    pub fn new<A>(elems: A) -> Tabs where A: AsRef<[Handle]> {
+        let mut carray = slice_to_ih_array!(elems.as_ref());
+        unsafe { Tabs::from_raw(iup_sys::IupTabsv(carray.as_mut_ptr())) }
+    }
+
+    /// Sets the currently visible tab by its position.
+    pub fn set_current_tab(&mut self, pos: usize) -> Self {
+        self.set_attrib("VALUEPOS", pos.to_string())
+    }
+
+    /// Gets the position of the currently visible tab.
+    pub fn current_tab(&self) -> usize {
+        self.attrib_parse("VALUEPOS").unwrap_or(0)
+    }
+
+    /// Gets the number of tabs.
+    pub fn tab_count(&self) -> usize {
+        self.attrib_parse("COUNT").unwrap_or(0)
+    }
+
+    /// Sets the title of the tab at the specified position.
+    ///
+    /// This is the TABTITLEn attribute, it can also be set as the TABTITLE attribute of the child.
+    pub fn set_tab_title<S: Into<String>>(&mut self, pos: usize, title: S) -> Self {
+        self.set_attrib_id("TABTITLE", pos as i32, title)
+    }
+
+    /// Gets the title of the tab at the specified position.
+    pub fn tab_title(&self, pos: usize) -> Option<String> {
+        self.attrib_id("TABTITLE", pos as i32)
+    }
+}
+
+impl_widget_container!(Tabs, "tabs");
+impl ::callback::MapCb for Tabs {}
+impl ::callback::UnmapCb for Tabs {}
+impl ::callback::GetFocusCb for Tabs {}
+impl ::callback::KillFocusCb for Tabs {}
+impl ::callback::key::KAnyCb for Tabs {}
+impl ::callback::key::KeyActionCb for Tabs {}
+impl ::callback::EnterWindowCb for Tabs {}
+impl ::callback::LeaveWindowCb for Tabs {}
+impl ::callback::HelpCb for Tabs {}
+impl ::attribute::BgColorAttribute for Tabs {}
+impl ::attribute::FgColorAttribute for Tabs {}
+impl ::attribute::TipAttribute for Tabs {}
+
+/// See the `TabChangeCb` documentation.
+impl self::TabChangeCb for Tabs {}
+
+impl_callback! {
+    #[doc="Called when the user shifts the active tab."]
+    #[doc=""]
+    #[doc="The first `Handle` parameter is the child of the new tab and the second is the child"]
+    #[doc="of the previously active tab."]
+    #[doc=""]
+    #[doc="Not called when the current tab is changed programmatically."]
+    pub trait TabChangeCb where Self: Element {
+        let name = "TABCHANGE_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, new_tab: *mut iup_sys::Ihandle,
+                           old_tab: *mut iup_sys::Ihandle) -> CallbackReturn;
+        fn set_tabchange_cb<F: Callback(Self, Handle, Handle)>(&mut self, cb: F) -> Self;
+        fn remove_tabchange_cb(&mut self) -> Option<Box<_>>;
+    }
+}
@@ -4,27 +4,55 @@ use std::{ptr};
 
 use Element;
 
-/// See the [IUP Text Documentation][1].
-/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptext.html
-pub struct Text(*mut iup_sys::Ihandle);
+/// Methods shared by the text editing elements, `Text` and `MultiLine`.
+pub trait TextElement : Element {
+    /// Sets the text of the element.
+    fn set_value<S: Into<String>>(&mut self, value: S) -> Self {
+        self.set_attrib("VALUE", value)
+    }
 
-impl Text {
-    /// Creates a editable text-field.
-    pub fn new() -> Text {
-        unsafe { Text::from_raw(iup_sys::IupText(ptr::null_mut())) }
+    /// Gets the text of the element.
+    fn value(&self) -> String {
+        self.attrib("VALUE").unwrap_or_default()
     }
 
-    /// Creates a spin control.
+    /// Sets a mask that will filter the text input.
     ///
-    /// The spin increments and decrements an integer number. 
-    pub fn new_spin() -> Text {
-        Text::new().set_attrib_data("SPIN", cstr!("YES") as *const _)
+    /// See the [IUP Mask Documentation][1] for the mask syntax.
+    /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/attrib/iup_mask.html
+    fn set_mask<S: Into<String>>(&mut self, mask: S) -> Self {
+        self.set_attrib("MASK", mask)
+    }
+
+    /// Sets the maximum number of characters the text can contain.
+    ///
+    /// This is the NC attribute. Setting it to zero lets the text grow to the system limit.
+    fn set_max_size(&mut self, nc: usize) -> Self {
+        self.set_attrib("NC", nc.to_string())
+    }
+
+    /// Gets the position of the caret (starts from 0).
+    fn caret(&self) -> usize {
+        self.attrib_parse("CARETPOS").unwrap_or(0)
+    }
+
+    /// Sets the position of the caret (starts from 0).
+    fn set_caret(&mut self, pos: usize) -> Self {
+        self.set_attrib("CARETPOS", pos.to_string())
+    }
+
+    /// Gets the selected interval as a (start, end) pair of positions or `None` if there's
+    /// no selection.
+    ///
+    /// Positions start from 0 and `end` is the position after the last selected character.
+    fn selection(&self) -> Option<(usize, usize)> {
+        self.attrib_int_pair("SELECTIONPOS").map(|(a, b)| (a as usize, b as usize))
     }
 
     /// Converts a (lin, col) character positioning into an absolute position.
     ///
     /// lin and col starts at 1, pos starts at 0. For single line controls pos is always *col-1*.
-    pub fn convert_lincol_to_pos(&self, lin: i32, col: i32) -> usize {
+    fn convert_lincol_to_pos(&self, lin: i32, col: i32) -> usize {
         unsafe {
             let mut r: c_int = 0;
             iup_sys::IupTextConvertLinColToPos(self.raw(), lin, col, &mut r);
@@ -36,7 +64,7 @@ impl Text {
     ///
     /// lin and col starts at 1, pos starts at 0.
     /// For single line controls lin is always 1, and col is always *pos+1*.
-    pub fn convert_pos_to_lincol(&self, pos: usize) -> (i32, i32) {
+    fn convert_pos_to_lincol(&self, pos: usize) -> (i32, i32) {
         unsafe {
             let (mut lin, mut col): (c_int, c_int) = (0, 0);
             iup_sys::IupTextConvertPosToLinCol(self.raw(), pos as c_int, &mut lin, &mut col);
@@ -45,8 +73,36 @@ impl Text {
     }
 }
 
+/// See the [IUP Text Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptext.html
+pub struct Text(*mut iup_sys::Ihandle);
+
+impl Text {
+    /// Creates a editable text-field.
+    pub fn new() -> Text {
+        unsafe { Text::from_raw(iup_sys::IupText(ptr::null_mut())) }
+    }
+
+    /// Creates a spin control.
+    ///
+    /// The spin increments and decrements an integer number. 
+    pub fn new_spin() -> Text {
+        Text::new().set_attrib_data("SPIN", cstr!("YES") as *const _)
+    }
+
+    /// Sets whether the typed characters are hidden, as in a password entry.
+    ///
+    /// This is the PASSWORD attribute and can only be set before the control is mapped.
+    pub fn set_password(&mut self, password: bool) -> Self {
+        self.set_attrib("PASSWORD", if password { "YES" } else { "NO" })
+    }
+}
+
 impl_widget!(Text, "text");
 
+/// See the `TextElement` documentation.
+impl self::TextElement for Text {}
+
 /// Returns a position in the string.
 impl ::element::ConvertXYToPos for Text {}
 
@@ -54,10 +110,15 @@ impl ::callback::MapCb for Text {}
 impl ::callback::UnmapCb for Text {}
 impl ::callback::GetFocusCb for Text {}
 impl ::callback::KillFocusCb for Text {}
+impl ::callback::key::KAnyCb for Text {}
+impl ::callback::key::KeyActionCb for Text {}
 impl ::callback::EnterWindowCb for Text {}
 impl ::callback::LeaveWindowCb for Text {}
 impl ::callback::HelpCb for Text {}
-// TODO impl K_ callbacks when it's implemented.
+impl ::attribute::BgColorAttribute for Text {}
+impl ::attribute::FgColorAttribute for Text {}
+impl ::attribute::TipAttribute for Text {}
+impl ::attribute::DropTargetAttribute for Text {}
 
 // TODO impl future DragSource and DragTarget traits.
 
@@ -1,8 +1,43 @@
 use iup_sys;
 use std::ptr;
 use std::ffi::CString;
+use libc::{c_char, c_int};
 
 use Element;
+use callback::IntoRust;
+
+/// The state of a toggle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ToggleState {
+    /// The toggle is checked.
+    On,
+    /// The toggle is unchecked.
+    Off,
+    /// The third state of 3-state toggles, neither checked nor unchecked.
+    NotDef,
+}
+
+impl ToggleState {
+    #[doc(hidden)]
+    pub fn as_cstr(self) -> *const c_char {
+        use self::ToggleState::*;
+        match self {
+            On => cstr!("ON"),
+            Off => cstr!("OFF"),
+            NotDef => cstr!("NOTDEF"),
+        }
+    }
+}
+
+impl IntoRust<ToggleState> for c_int {
+    fn into_rust(self) -> ToggleState {
+        match self {
+            1 => ToggleState::On,
+            0 => ToggleState::Off,
+            _ => ToggleState::NotDef,
+        }
+    }
+}
 
 /// See the [IUP Toggle Documentation][1].
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptoggle.html
@@ -19,6 +54,31 @@ impl Toggle {
         let ctitle = CString::new(title.into()).unwrap();
         unsafe { Toggle::from_raw(iup_sys::IupToggle(ctitle.as_ptr(), ptr::null_mut())) }
     }
+
+    /// Sets the state of the toggle.
+    ///
+    /// `ToggleState::NotDef` is only valid for 3-state toggles (3STATE=YES), but setting the
+    /// state of a toggle to `ToggleState::On` inside a radio also sets the previously on toggle
+    /// of the radio to `ToggleState::Off`.
+    pub fn set_value(&mut self, state: ToggleState) -> Self {
+        self.set_attrib_data("VALUE", state.as_cstr() as *const _)
+    }
+
+    /// Gets the state of the toggle.
+    pub fn value(&self) -> ToggleState {
+        match self.attrib("VALUE").as_ref().map(|s| s.as_ref()) {
+            Some("ON") => ToggleState::On,
+            Some("NOTDEF") => ToggleState::NotDef,
+            _ => ToggleState::Off,
+        }
+    }
+
+    /// Checks whether the toggle is inside a radio.
+    ///
+    /// This is the RADIO attribute and is only available after the toggle is mapped.
+    pub fn is_radio(&self) -> bool {
+        self.attrib_bool("RADIO").unwrap_or(false)
+    }
 }
 
 impl_widget!(Toggle, "toggle");
@@ -26,10 +86,15 @@ impl ::callback::MapCb for Toggle {}
 impl ::callback::UnmapCb for Toggle {}
 impl ::callback::GetFocusCb for Toggle {}
 impl ::callback::KillFocusCb for Toggle {}
+impl ::callback::key::KAnyCb for Toggle {}
+impl ::callback::key::KeyActionCb for Toggle {}
 impl ::callback::EnterWindowCb for Toggle {}
 impl ::callback::LeaveWindowCb for Toggle {}
 impl ::callback::HelpCb for Toggle {}
-// TODO impl K_ callbacks when it's implemented.
+impl ::attribute::TitleAttribute for Toggle {}
+impl ::attribute::BgColorAttribute for Toggle {}
+impl ::attribute::FgColorAttribute for Toggle {}
+impl ::attribute::TipAttribute for Toggle {}
 
 /// Called after the value was interactively changed by the user.
 ///
@@ -41,13 +106,13 @@ impl self::ToggleAction for Toggle {}
 impl_callback! {
     #[doc="Action generated when the toggle's state (on/off) was changed."]
     #[doc=""]
-    #[doc="The callback boolean parameter represents the state the toggle was switched to."]
+    #[doc="The `ToggleState` parameter is the state the toggle was switched to."]
     #[doc=""]
     #[doc="`CallbackReturn::Close` will be processed"]
     pub trait ToggleAction where Self: Element {
         let name = "ACTION";
         extern fn listener(ih: *mut iup_sys::Ihandle, state: c_int) -> CallbackReturn;
-        fn set_action<F: Callback(Self, bool)>(&mut self, cb: F) -> Self;
+        fn set_action<F: Callback(Self, ToggleState)>(&mut self, cb: F) -> Self;
         fn remove_action(&mut self) -> Option<Box<_>>;
     }
 }
@@ -0,0 +1,187 @@
+use iup_sys;
+use libc::{c_void, c_int};
+use std::any::Any;
+use std::mem;
+
+use Element;
+
+/// A tree containing nodes of branches or leaves.
+///
+/// Each node is identified by an id, which is its position in the tree when it is fully
+/// expanded starting at 0 (the root, created by default). Inserting or removing nodes changes
+/// the ids of the nodes after them.
+///
+/// Both branches and leaves can have an associated text.
+///
+/// See the [IUP Tree Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iuptree.html
+pub struct Tree(*mut iup_sys::Ihandle);
+
+impl Tree {
+    /// Creates a tree containing only the root node.
+    pub fn new() -> Tree {
+        unsafe { Tree::from_raw(iup_sys::IupTree()) }
+    }
+
+    /// Adds a new branch and returns its id.
+    ///
+    /// The new node is inserted as the first child of the `parent_id` node when it is a branch,
+    /// or right after it (at the same depth) when it is a leaf. Use -1 to add before the root.
+    ///
+    /// Nodes can only be added after the tree is mapped.
+    pub fn add_branch<S: Into<String>>(&mut self, parent_id: i32, title: S) -> Option<i32> {
+        self.set_attrib_id("ADDBRANCH", parent_id, title);
+        self.last_add_node()
+    }
+
+    /// Adds a new leaf and returns its id.
+    ///
+    /// See `add_branch` for the semantics of `parent_id`.
+    ///
+    /// Nodes can only be added after the tree is mapped.
+    pub fn add_leaf<S: Into<String>>(&mut self, parent_id: i32, title: S) -> Option<i32> {
+        self.set_attrib_id("ADDLEAF", parent_id, title);
+        self.last_add_node()
+    }
+
+    /// Id of the last node added by `add_branch` or `add_leaf`.
+    fn last_add_node(&self) -> Option<i32> {
+        self.attrib_parse("LASTADDNODE")
+    }
+
+    /// Removes the specified node and all its children.
+    pub fn remove_node(&mut self, id: i32) -> Self {
+        self.set_attrib_id("DELNODE", id, "SELECTED")
+    }
+
+    /// Removes every node of the tree.
+    pub fn clear(&mut self) -> Self {
+        self.set_attrib("DELNODE", "ALL")
+    }
+
+    /// Gets the total number of nodes in the tree.
+    pub fn node_count(&self) -> u32 {
+        self.attrib_parse("COUNT").unwrap_or(0)
+    }
+
+    /// Sets the text of the specified node.
+    pub fn set_node_title<S: Into<String>>(&mut self, id: i32, title: S) -> Self {
+        self.set_attrib_id("TITLE", id, title)
+    }
+
+    /// Gets the text of the specified node or `None` if the node does not exist.
+    pub fn node_title(&self, id: i32) -> Option<String> {
+        self.attrib_id("TITLE", id)
+    }
+
+    /// Expands (`true`) or collapses (`false`) the specified branch.
+    pub fn expand(&mut self, id: i32, open: bool) -> Self {
+        self.set_attrib_id("STATE", id, if open { "EXPANDED" } else { "COLLAPSED" })
+    }
+
+    /// Selects the specified node, also moving the focus to it.
+    pub fn set_selected(&mut self, id: i32) -> Self {
+        self.set_attrib("VALUE", id.to_string())
+    }
+
+    /// Gets the id of the node with the focus or `None` if the tree is empty.
+    pub fn selected(&self) -> Option<i32> {
+        self.attrib_parse("VALUE").and_then(|id| if id < 0 { None } else { Some(id) })
+    }
+
+    /// Associates Rust data with the specified node, replacing any previous data.
+    ///
+    /// The data follows the node when other nodes are inserted or removed and is dropped when the
+    /// node gets removed. If the node does not exist *(or the tree is not mapped)* the data is
+    /// dropped immediately.
+    pub fn set_node_data<T: Any>(&mut self, id: i32, data: Box<T>) -> Self {
+        let data: Box<Box<dyn Any>> = Box::new(data);
+        unsafe {
+            let old = iup_sys::IupTreeGetUserId(self.raw(), id);
+            let new = Box::into_raw(data) as *mut c_void;
+            if iup_sys::IupTreeSetUserId(self.raw(), id, new) != 0 {
+                let cb = mem::transmute::<extern "C" fn(*mut iup_sys::Ihandle, *mut c_void) -> c_int,
+                                          iup_sys::Icallback>(on_node_removed);
+                iup_sys::IupSetCallback(self.raw(), cstr!("NODEREMOVED_CB"), cb);
+                drop_node_data(old);
+            } else {
+                drop_node_data(new);
+            }
+        }
+        *self
+    }
+
+    /// Gets a copy of the data associated with the specified node or `None` if the node has no
+    /// data or the data is not of type `T`.
+    ///
+    /// A copy is returned because the data is owned by the node and may be dropped by any
+    /// handle to the tree.
+    pub fn node_data<T: Any + Clone>(&self, id: i32) -> Option<T> {
+        match unsafe { iup_sys::IupTreeGetUserId(self.raw(), id) } {
+            ptr if ptr.is_null() => None,
+            ptr => unsafe { (*(ptr as *const Box<dyn Any>)).downcast_ref::<T>().cloned() },
+        }
+    }
+}
+
+impl Default for Tree {
+    fn default() -> Tree {
+        Tree::new()
+    }
+}
+
+/// Frees data stored by `Tree::set_node_data`.
+unsafe fn drop_node_data(userdata: *mut c_void) {
+    if !userdata.is_null() {
+        drop(Box::from_raw(userdata as *mut Box<dyn Any>));
+    }
+}
+
+/// Called by IUP right before a node is removed.
+extern "C" fn on_node_removed(_ih: *mut iup_sys::Ihandle, userdata: *mut c_void) -> c_int {
+    unsafe { drop_node_data(userdata) };
+    iup_sys::IUP_DEFAULT
+}
+
+impl_widget!(Tree, "tree");
+
+impl ::callback::MapCb for Tree {}
+impl ::callback::UnmapCb for Tree {}
+impl ::callback::GetFocusCb for Tree {}
+impl ::callback::KillFocusCb for Tree {}
+impl ::callback::key::KAnyCb for Tree {}
+impl ::callback::key::KeyActionCb for Tree {}
+impl ::callback::EnterWindowCb for Tree {}
+impl ::callback::LeaveWindowCb for Tree {}
+impl ::callback::HelpCb for Tree {}
+impl ::attribute::BgColorAttribute for Tree {}
+impl ::attribute::FgColorAttribute for Tree {}
+impl ::attribute::TipAttribute for Tree {}
+impl ::attribute::DropTargetAttribute for Tree {}
+
+/// Action generated when one or more files are dropped in the tree.
+impl ::callback::DropFilesCb for Tree {}
+
+/// See the `TreeSelectionCb` documentation.
+impl self::TreeSelectionCb for Tree {}
+
+// TODO:
+// BRANCHOPEN_CB
+// BRANCHCLOSE_CB
+// EXECUTELEAF_CB
+// RENAME_CB
+// RIGHTCLICK_CB
+// MULTISELECTION_CB
+
+impl_callback! {
+    #[doc="Action generated when a node is selected or deselected."]
+    #[doc=""]
+    #[doc="The `i32` parameter is the id of the node."]
+    #[doc="The `bool` parameter is whether the node was selected (`true`) or deselected (`false`)."]
+    pub trait TreeSelectionCb where Self: Element {
+        let name = "SELECTION_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, id: c_int, status: c_int) -> CallbackReturn;
+        fn set_selection_cb<F: Callback(Self, i32, bool)>(&mut self, cb: F) -> Self;
+        fn remove_selection_cb(&mut self) -> Option<Box<_>>;
+    }
+}
@@ -0,0 +1,100 @@
+use iup_sys;
+
+use Element;
+use Orientation;
+
+/// A valuator, that is, a control that selects a value in a limited interval by dragging
+/// a handle.
+///
+/// See the [IUP Val Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupval.html
+pub struct Val(*mut iup_sys::Ihandle);
+
+impl Val {
+    /// Creates a valuator in the specified orientation.
+    pub fn new(orient: Orientation) -> Val {
+        unsafe { Val::from_raw(iup_sys::IupVal(orient.as_cstr())) }
+    }
+
+    /// Creates a horizontal valuator.
+    pub fn horizontal() -> Val {
+        Val::new(Orientation::Horizontal)
+    }
+
+    /// Creates a vertical valuator.
+    pub fn vertical() -> Val {
+        Val::new(Orientation::Vertical)
+    }
+
+    /// Sets the current value of the valuator.
+    ///
+    /// The value is clamped to the `[min, max]` interval of the valuator.
+    pub fn set_value(&mut self, value: f64) -> Self {
+        let value = value.max(self.min()).min(self.max());
+        self.set_attrib("VALUE", value.to_string())
+    }
+
+    /// Gets the current value of the valuator.
+    pub fn value(&self) -> f64 {
+        self.attrib_parse("VALUE").unwrap_or_else(|| self.min())
+    }
+
+    /// Sets the minimum value of the valuator. Default is 0.
+    pub fn set_min(&mut self, min: f64) -> Self {
+        self.set_attrib("MIN", min.to_string())
+    }
+
+    /// Gets the minimum value of the valuator.
+    pub fn min(&self) -> f64 {
+        self.attrib_parse("MIN").unwrap_or(0.0)
+    }
+
+    /// Sets the maximum value of the valuator. Default is 1.
+    pub fn set_max(&mut self, max: f64) -> Self {
+        self.set_attrib("MAX", max.to_string())
+    }
+
+    /// Gets the maximum value of the valuator.
+    pub fn max(&self) -> f64 {
+        self.attrib_parse("MAX").unwrap_or(1.0)
+    }
+
+    /// Sets the increment used by the keyboard arrows, relative to the `[min, max]` interval.
+    ///
+    /// Must be in the `[0, 1]` interval, default is 0.01.
+    pub fn set_step(&mut self, step: f64) -> Self {
+        self.set_attrib("STEP", step.to_string())
+    }
+}
+
+impl_widget!(Val, "val");
+impl ::callback::MapCb for Val {}
+impl ::callback::UnmapCb for Val {}
+impl ::callback::GetFocusCb for Val {}
+impl ::callback::KillFocusCb for Val {}
+impl ::callback::key::KAnyCb for Val {}
+impl ::callback::key::KeyActionCb for Val {}
+impl ::callback::EnterWindowCb for Val {}
+impl ::callback::LeaveWindowCb for Val {}
+impl ::callback::HelpCb for Val {}
+impl ::attribute::BgColorAttribute for Val {}
+impl ::attribute::TipAttribute for Val {}
+
+/// See the `ValValueChangedCb` documentation.
+impl self::ValValueChangedCb for Val {}
+
+impl_callback! {
+    #[doc="Called after the value was interactively changed by the user."]
+    #[doc=""]
+    #[doc="The `f64` parameter is the current value of the valuator."]
+    pub trait ValValueChangedCb where Self: Element {
+        let name = "VALUECHANGED_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle) -> CallbackReturn;
+        fn set_valuechanged_cb<F: Callback(Self, f64)>(&mut self, cb: F) -> Self;
+        fn remove_valuechanged_cb(&mut self) -> Option<Box<_>>;
+
+        fn resolve_args(elem: Self,) -> (Self, f64) {
+            (elem, elem.attrib_parse("VALUE").unwrap_or(0.0))
+        }
+    }
+}
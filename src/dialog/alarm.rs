@@ -13,6 +13,9 @@ pub enum AlarmButton {
 ///
 /// Returns the pressed button.
 ///
+/// The first button is required, `None` buttons are not shown. This function blocks until
+/// the user presses a button and, as any other dialog, must be called within `with_iup`.
+///
 /// See the [IUP Alarm Documentation][1].
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupalarm.html
 pub fn alarm<S1, S2>(title: S1, message: S2, button1: String,
@@ -28,8 +31,8 @@ pub fn alarm<S1, S2>(title: S1, message: S2, button1: String,
 	let pressed = unsafe {
 		iup_sys::IupAlarm(ctitle.as_ptr(), cmessage.as_ptr(),
 			              cbutton1.as_ptr(),
-						  cbutton2.map_or(ptr::null(), |cs| cs.as_ptr()),
-						  cbutton3.map_or(ptr::null(), |cs| cs.as_ptr()))
+						  cbutton2.as_ref().map_or(ptr::null(), |cs| cs.as_ptr()),
+						  cbutton3.as_ref().map_or(ptr::null(), |cs| cs.as_ptr()))
 	};
 
 	match pressed {
@@ -0,0 +1,97 @@
+use iup_sys;
+
+use Element;
+use attribute::Color;
+use dialog::{Dialog, DialogElement, DialogPos};
+
+/// A predefined dialog for selecting a color.
+///
+/// This dialog can be shown with the `popup` method only, or with the `ColorDlg::open` helper.
+///
+/// See the [IUP ColorDlg Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupcolordlg.html
+pub struct ColorDlg(*mut iup_sys::Ihandle);
+
+impl ColorDlg {
+    /// Creates a color selection dialog.
+    pub fn new() -> ColorDlg {
+        unsafe { ColorDlg::from_raw(iup_sys::IupColorDlg()) }
+    }
+
+    /// Sets the initial color of the dialog.
+    ///
+    /// If the color is not opaque its alpha is set as well, which also shows the alpha
+    /// selection (see `ColorDlg::set_show_alpha`). An opaque color clears any previous alpha.
+    pub fn set_color<C: Into<Color>>(&mut self, color: C) -> Self {
+        let color = color.into();
+        if color.a != 255 {
+            self.set_attrib("ALPHA", color.a.to_string());
+        } else {
+            self.clear_attrib("ALPHA");
+        }
+        self.set_attrib_color("VALUE", color)
+    }
+
+    /// Gets the color selected in the dialog.
+    ///
+    /// The alpha of the color is `255` unless ALPHA is set.
+    pub fn color(&self) -> Color {
+        let rgb = self.attrib_color("VALUE").unwrap_or(Color::rgb(0, 0, 0));
+        Color::rgba(rgb.r, rgb.g, rgb.b, self.attrib_parse("ALPHA").unwrap_or(255))
+    }
+
+    /// Sets whether the alpha selection is shown.
+    pub fn set_show_alpha(&mut self, show: bool) -> Self {
+        self.set_attrib("SHOWALPHA", if show { "YES" } else { "NO" })
+    }
+
+    /// Sets whether the hexadecimal representation of the color is shown.
+    pub fn set_show_hex(&mut self, show: bool) -> Self {
+        self.set_attrib("SHOWHEX", if show { "YES" } else { "NO" })
+    }
+
+    /// Sets whether a table of predefined colors is shown.
+    pub fn set_show_colortable(&mut self, show: bool) -> Self {
+        self.set_attrib("SHOWCOLORTABLE", if show { "YES" } else { "NO" })
+    }
+
+    /// Shows the dialog modally, centered to the parent dialog if any, and returns the
+    /// selected color or `None` if the user cancelled the dialog.
+    pub fn open(&mut self, parent: Option<Dialog>) -> Option<Color> {
+        if let Some(parent) = parent {
+            self.set_attrib_handle("PARENTDIALOG", parent);
+        }
+        match self.popup(DialogPos::CenterParent, DialogPos::CenterParent) {
+            Ok(_) if self.attrib("STATUS").map(|s| s == "1").unwrap_or(false) => Some(self.color()),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ColorDlg {
+    fn default() -> ColorDlg {
+        ColorDlg::new()
+    }
+}
+
+impl_dialog!(ColorDlg, "colordlg");
+impl ::callback::HelpCb for ColorDlg {}
+impl ::attribute::TitleAttribute for ColorDlg {}
+
+/// Shows a simple modal dialog for selecting a color, initially showing `initial` (or black).
+///
+/// Returns the selected color or `None` if the user cancelled the dialog. The alpha of the
+/// initial color is ignored and the alpha of the selected color is always `255`.
+///
+/// This dialog is simpler than `ColorDlg`, which should be preferred for more control.
+///
+/// See the [IUP GetColor Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupgetcolor.html
+pub fn prompt_color(x: DialogPos, y: DialogPos, initial: Option<Color>) -> Option<Color> {
+    let initial = initial.unwrap_or(Color::rgb(0, 0, 0));
+    let (mut r, mut g, mut b) = (initial.r, initial.g, initial.b);
+    match unsafe { iup_sys::IupGetColor(x.to_raw_x(), y.to_raw_y(), &mut r, &mut g, &mut b) } {
+        1 => Some(Color::rgb(r, g, b)),
+        _ => None,
+    }
+}
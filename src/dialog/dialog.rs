@@ -2,7 +2,14 @@ use iup_sys;
 use libc::{c_char, c_int};
 use std::ptr;
 
+use Handle;
 use Element;
+use element::Widget;
+use dialog::{DialogElement, DialogPos};
+use menu::Menu;
+use image::ImageElement;
+use attribute::Size;
+use control::Button;
 use callback::IntoRust;
 use callback::button::{MouseButton, MouseButtonState};
 
@@ -24,17 +31,305 @@ impl Dialog {
     pub fn new_empty() -> Dialog {
         unsafe { Dialog::from_raw(iup_sys::IupDialog(ptr::null_mut())) }
     }
+
+    /// Sets the menu bar of the dialog.
+    ///
+    /// The menu is automatically destroyed when the dialog is destroyed.
+    pub fn set_menu(&mut self, menu: Menu) -> Self {
+        self.set_attrib_handle("MENU", menu)
+    }
+
+    /// Gets the menu bar of the dialog.
+    pub fn menu(&self) -> Option<Menu> {
+        self.attrib_handle("MENU").and_then(|h| h.try_downcast::<Menu>().ok())
+    }
+
+    /// Sets the icon of the dialog.
+    ///
+    /// On Windows the icon is shown in the title bar and in the taskbar, on GTK and Motif it
+    /// is passed to the window manager, which decides where it is shown.
+    pub fn set_icon<I: ImageElement>(&mut self, icon: &I) -> Self {
+        self.set_attrib_handle("ICON", *icon)
+    }
+
+    /// Sets the icon of the dialog by the handle name of an image.
+    ///
+    /// On Windows it can also be the name of an icon resource of the executable.
+    pub fn set_icon_by_name<S: Into<String>>(&mut self, name: S) -> Self {
+        self.set_attrib("ICON", name)
+    }
+
+    /// Gets the icon image of the dialog.
+    pub fn icon(&self) -> Option<Handle> {
+        self.attrib_handle("ICON")
+    }
+
+    /// Changes the stacking order of the dialog relative to the other windows.
+    ///
+    /// This is the write-only ZORDER attribute, only meaningful when the dialog is mapped.
+    pub fn set_zorder(&mut self, z: ZOrder) -> Self {
+        self.set_attrib("ZORDER", match z {
+            ZOrder::Top => "TOP",
+            ZOrder::Bottom => "BOTTOM",
+        })
+    }
+
+    /// Places the dialog on top of the other windows, same as `set_zorder(ZOrder::Top)`.
+    pub fn bring_to_front(&mut self) -> Self {
+        self.set_zorder(ZOrder::Top)
+    }
+
+    /// Places the dialog behind the other windows, same as `set_zorder(ZOrder::Bottom)`.
+    pub fn send_to_back(&mut self) -> Self {
+        self.set_zorder(ZOrder::Bottom)
+    }
+
+    /// Makes the dialog occupy the whole screen over any system bars, with no decorations.
+    ///
+    /// This is the FULLSCREEN attribute, it can be set before or after the dialog is shown.
+    pub fn set_fullscreen(&mut self, on: bool) -> Self {
+        self.set_attrib("FULLSCREEN", if on { "YES" } else { "NO" })
+    }
+
+    /// Checks whether the dialog is in full screen mode.
+    pub fn is_fullscreen(&self) -> bool {
+        self.attrib("FULLSCREEN").map(|s| s == "YES").unwrap_or(false)
+    }
+
+    /// Sets whether the dialog is maximized the next time it is shown.
+    ///
+    /// This sets the PLACEMENT attribute, which is only applied by `Widget::show` (or
+    /// `DialogElement::showxy`) and is reset to normal afterwards. Use `Dialog::maximize` to
+    /// maximize a dialog already visible.
+    pub fn set_maximize(&mut self, on: bool) -> Self {
+        self.set_placement(if on { Placement::Maximized } else { Placement::Normal })
+    }
+
+    /// Maximizes the dialog, showing it if hidden.
+    pub fn maximize(&mut self) -> Result<(), String> {
+        self.show_maximized()
+    }
+
+    /// Sets how the dialog is placed the next time it is shown.
+    ///
+    /// This is the PLACEMENT attribute, it is only applied by `Widget::show` (or
+    /// `DialogElement::showxy`) and reset to `Placement::Normal` afterwards.
+    pub fn set_placement(&mut self, p: Placement) -> Self {
+        self.set_attrib("PLACEMENT", p.as_str())
+    }
+
+    /// Gets how the dialog will be placed the next time it is shown.
+    pub fn placement(&self) -> Placement {
+        match self.attrib("PLACEMENT").as_ref().map(|s| s.as_ref()) {
+            Some("MAXIMIZED") => Placement::Maximized,
+            Some("MINIMIZED") => Placement::Minimized,
+            Some("FULL") => Placement::Full,
+            _ => Placement::Normal,
+        }
+    }
+
+    /// Shows the dialog maximized.
+    pub fn show_maximized(&mut self) -> Result<(), String> {
+        self.set_placement(Placement::Maximized);
+        self.show()
+    }
+
+    /// Shows the dialog minimized.
+    pub fn show_minimized(&mut self) -> Result<(), String> {
+        self.set_placement(Placement::Minimized);
+        self.show()
+    }
+
+    /// Sets the minimum size of the dialog in pixels when resized by the user.
+    ///
+    /// This is the MINSIZE attribute, a `None` dimension is left unconstrained.
+    pub fn set_min_size(&mut self, size: Size) -> Self {
+        self.set_attrib("MINSIZE", size.to_string())
+    }
+
+    /// Gets the minimum size of the dialog in pixels.
+    pub fn min_size(&self) -> Size {
+        self.attrib_parse("MINSIZE").unwrap_or_default()
+    }
+
+    /// Sets the maximum size of the dialog in pixels when resized by the user.
+    ///
+    /// This is the MAXSIZE attribute, a `None` dimension is left unconstrained.
+    pub fn set_max_size(&mut self, size: Size) -> Self {
+        self.set_attrib("MAXSIZE", size.to_string())
+    }
+
+    /// Gets the maximum size of the dialog in pixels.
+    pub fn max_size(&self) -> Size {
+        self.attrib_parse("MAXSIZE").unwrap_or_default()
+    }
+}
+
+/// How a dialog is placed when shown, see `Dialog::set_placement`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Placement {
+    /// The size and position set by the application.
+    Normal,
+    Maximized,
+    Minimized,
+    /// Occupies the whole screen but, unlike `Dialog::set_fullscreen`, keeps the decorations.
+    Full,
+}
+
+impl Placement {
+    #[doc(hidden)]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Placement::Normal => "NORMAL",
+            Placement::Maximized => "MAXIMIZED",
+            Placement::Minimized => "MINIMIZED",
+            Placement::Full => "FULL",
+        }
+    }
+}
+
+/// The stacking order of a dialog, see `Dialog::set_zorder`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ZOrder {
+    Top,
+    Bottom,
 }
 
 impl_dialog!(Dialog, "dialog");
+
+/// A builder for dialogs, accumulating attributes to be set when the dialog is built.
+///
+/// The same can be done by chaining `Element::set_attrib` calls in the constructed `Dialog`,
+/// the builder just provides a typed interface to the most common dialog attributes.
+///
+/// # Example
+/// ```ignore
+/// let dialog = DialogBuilder::new()
+///                 .title("Hello")
+///                 .size(200, 100)
+///                 .resize(false)
+///                 .build(Label::with_title("Hello world!"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DialogBuilder {
+    attribs: Vec<(String, String)>,
+    handles: Vec<(String, Handle)>,
+    center: bool,
+}
+
+impl DialogBuilder {
+    /// Creates a builder with no attributes.
+    pub fn new() -> DialogBuilder {
+        Default::default()
+    }
+
+    fn attrib<S: Into<String>>(mut self, name: &str, value: S) -> DialogBuilder {
+        self.attribs.push((name.into(), value.into()));
+        self
+    }
+
+    fn attrib_bool(self, name: &str, value: bool) -> DialogBuilder {
+        self.attrib(name, if value { "YES" } else { "NO" })
+    }
+
+    fn attrib_handle<E: Element>(mut self, name: &str, elem: E) -> DialogBuilder {
+        self.handles.push((name.into(), Handle::from_raw(elem.raw())));
+        self
+    }
+
+    /// Sets the title of the dialog (TITLE).
+    pub fn title<S: Into<String>>(self, title: S) -> DialogBuilder {
+        self.attrib("TITLE", title)
+    }
+
+    /// Sets the size of the dialog in units proportional to the size of a character (SIZE).
+    pub fn size(self, width: i32, height: i32) -> DialogBuilder {
+        self.attrib("SIZE", Size::new(Some(width), Some(height)).to_string())
+    }
+
+    /// Sets whether the dialog can be resized by the user (RESIZE).
+    pub fn resize(self, resize: bool) -> DialogBuilder {
+        self.attrib_bool("RESIZE", resize)
+    }
+
+    /// Sets whether the dialog has a maximize button (MAXBOX).
+    pub fn maxbox(self, maxbox: bool) -> DialogBuilder {
+        self.attrib_bool("MAXBOX", maxbox)
+    }
+
+    /// Sets whether the dialog has a minimize button (MINBOX).
+    pub fn minbox(self, minbox: bool) -> DialogBuilder {
+        self.attrib_bool("MINBOX", minbox)
+    }
+
+    /// Sets whether the dialog occupies the whole screen, with no decorations (FULLSCREEN).
+    pub fn fullscreen(self, fullscreen: bool) -> DialogBuilder {
+        self.attrib_bool("FULLSCREEN", fullscreen)
+    }
+
+    /// Sets how the dialog is placed when first shown (PLACEMENT).
+    pub fn placement(self, p: Placement) -> DialogBuilder {
+        self.attrib("PLACEMENT", p.as_str())
+    }
+
+    /// Centers the dialog on the screen when it is shown with `DialogBuilder::show`.
+    pub fn center(mut self) -> DialogBuilder {
+        self.center = true;
+        self
+    }
+
+    /// Sets the element that receives the focus when the dialog is first shown (STARTFOCUS).
+    pub fn start_focus<E: Element>(self, elem: E) -> DialogBuilder {
+        self.attrib_handle("STARTFOCUS", elem)
+    }
+
+    /// Sets the button activated when the user presses Enter (DEFAULTENTER).
+    pub fn default_enter(self, button: Button) -> DialogBuilder {
+        self.attrib_handle("DEFAULTENTER", button)
+    }
+
+    /// Sets the button activated when the user presses Esc (DEFAULTESC).
+    pub fn default_esc(self, button: Button) -> DialogBuilder {
+        self.attrib_handle("DEFAULTESC", button)
+    }
+
+    /// Creates a dialog with the specified child and sets the accumulated attributes on it.
+    pub fn build<E: Element>(&self, child: E) -> Dialog {
+        let mut dialog = Dialog::new(child);
+        for (name, value) in &self.attribs {
+            dialog.set_attrib(name.clone(), value.clone());
+        }
+        for (name, handle) in &self.handles {
+            dialog.set_attrib_handle(name.clone(), *handle);
+        }
+        dialog
+    }
+
+    /// Builds the dialog with the specified child and shows it, centered on the screen if
+    /// `DialogBuilder::center` was called.
+    pub fn show<E: Element>(&self, child: E) -> Result<Dialog, String> {
+        let mut dialog = self.build(child);
+        if self.center {
+            dialog.showxy(DialogPos::Center, DialogPos::Center)?;
+        } else {
+            dialog.show()?;
+        }
+        Ok(dialog)
+    }
+}
 impl ::callback::MapCb for Dialog {}
 impl ::callback::UnmapCb for Dialog {}
 impl ::callback::GetFocusCb for Dialog {}
 impl ::callback::KillFocusCb for Dialog {}
+impl ::callback::key::KAnyCb for Dialog {}
+impl ::callback::key::KeyActionCb for Dialog {}
 impl ::callback::EnterWindowCb for Dialog {}
 impl ::callback::LeaveWindowCb for Dialog {}
 impl ::callback::HelpCb for Dialog {}
-// TODO impl K_ callbacks when it's implemented.
+impl ::attribute::TitleAttribute for Dialog {}
+impl ::attribute::BgColorAttribute for Dialog {}
+impl ::attribute::CursorAttribute for Dialog {}
+impl ::attribute::DropTargetAttribute for Dialog {}
 
 /// Called right before the dialog is closed.
 impl ::callback::CloseCb for Dialog {}
@@ -113,8 +408,8 @@ impl_callback! {
     pub trait ShowCb where Self: Element {
         let name = "SHOW_CB";
         extern fn listener(ih: *mut iup_sys::Ihandle, state: c_int) -> CallbackReturn;
-        fn set_move_cb<F: Callback(Self, ShowState)>(&mut self, cb: F) -> Self;
-        fn remove_move_cb(&mut self) -> Option<Box<_>>;
+        fn set_show_cb<F: Callback(Self, ShowState)>(&mut self, cb: F) -> Self;
+        fn remove_show_cb(&mut self) -> Option<Box<_>>;
     }
 }
 
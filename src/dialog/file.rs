@@ -1,7 +1,43 @@
 use iup_sys;
+use libc::c_char;
 use std::path::{Path, PathBuf};
 
 use Element;
+use dialog::{Dialog, DialogElement, DialogPos};
+
+/// The kind of file dialog.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FileDlgType {
+    /// Selects files to be opened.
+    Open,
+    /// Selects a file to be saved.
+    Save,
+    /// Selects a directory.
+    Dir,
+}
+
+impl FileDlgType {
+    #[doc(hidden)]
+    pub fn as_cstr(self) -> *const c_char {
+        use self::FileDlgType::*;
+        match self {
+            Open => cstr!("OPEN"),
+            Save => cstr!("SAVE"),
+            Dir => cstr!("DIR"),
+        }
+    }
+}
+
+/// The result of `FileDlg::open`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FileDlgResult {
+    /// The selected files or directory.
+    Ok(Vec<PathBuf>),
+    /// The user cancelled the dialog.
+    Cancel,
+    /// The dialog could not be shown.
+    Error,
+}
 
 pub struct FileDlg(*mut iup_sys::Ihandle);
 
@@ -22,6 +58,53 @@ impl FileDlg {
         FileDlg::new().set_attrib_data("DIALOGTYPE", cstr!("DIR") as *const _)
     }
 
+    /// Sets the kind of the dialog. Default is `FileDlgType::Open`.
+    pub fn set_dialog_type(&mut self, ty: FileDlgType) -> Self {
+        self.set_attrib_data("DIALOGTYPE", ty.as_cstr() as *const _)
+    }
+
+    /// Sets the file name filters, separated by `;`, for example `"*.txt;*.md"`.
+    ///
+    /// This is the FILTER attribute. See also EXTFILTER for multiple named filters.
+    pub fn set_filter<S: Into<String>>(&mut self, filter: S) -> Self {
+        self.set_attrib("FILTER", filter)
+    }
+
+    /// Sets the description of the file filter, for example `"Text Files"`.
+    pub fn set_filter_info<S: Into<String>>(&mut self, info: S) -> Self {
+        self.set_attrib("FILTERINFO", info)
+    }
+
+    /// Sets the initial directory of the dialog.
+    pub fn set_directory<P: AsRef<Path>>(&mut self, dir: P) -> Self {
+        self.set_attrib("DIRECTORY", dir.as_ref().to_string_lossy().into_owned())
+    }
+
+    /// Sets whether multiple files can be selected. Only valid for `FileDlgType::Open`.
+    pub fn set_multiple_files(&mut self, multiple: bool) -> Self {
+        self.set_attrib("MULTIPLEFILES", if multiple { "YES" } else { "NO" })
+    }
+
+    /// Sets whether hidden files are shown. Only valid in GTK and Motif.
+    pub fn set_show_hidden(&mut self, show: bool) -> Self {
+        self.set_attrib("SHOWHIDDEN", if show { "YES" } else { "NO" })
+    }
+
+    /// Shows the dialog modally, centered to the parent dialog if any, and returns the
+    /// user selection.
+    pub fn open(&mut self, parent: Option<Dialog>) -> FileDlgResult {
+        if let Some(parent) = parent {
+            self.set_attrib_handle("PARENTDIALOG", parent);
+        }
+        if self.popup(DialogPos::CenterParent, DialogPos::CenterParent).is_err() {
+            return FileDlgResult::Error;
+        }
+        match self.attrib("STATUS").as_ref().map(|s| s.as_ref()) {
+            Some("0") | Some("1") => FileDlgResult::Ok(self.files().unwrap_or_default()),
+            _ => FileDlgResult::Cancel,
+        }
+    }
+
     pub fn files(&self) -> Option<Vec<PathBuf>> {
         self.attrib("VALUE").and_then(|value| {
             let values =  value.split_terminator('|').collect::<Vec<&str>>();
@@ -48,3 +131,4 @@ impl FileDlg {
 
 impl_dialog!(FileDlg, "filedlg");
 impl ::callback::HelpCb for FileDlg {}
+impl ::attribute::TitleAttribute for FileDlg {}
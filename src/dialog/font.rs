@@ -0,0 +1,62 @@
+use iup_sys;
+
+use Element;
+use dialog::{Dialog, DialogElement, DialogPos};
+
+/// A predefined dialog for selecting a font.
+///
+/// The font is a string in the IUP font format, `"<font face>, <font styles> <font size>"`,
+/// for example `"Times, Bold 18"`. This string can be given directly to the FONT attribute of
+/// any element, IUP converts it to the native font of the platform; the native font name of an
+/// element can then be read from its platform specific attribute (e.g. WINFONT or XFONT).
+///
+/// This dialog can be shown with the `popup` method only, or with the `FontDlg::open` helper.
+///
+/// See the [IUP FontDlg Documentation][1] and the [IUP FONT Documentation][2].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupfontdlg.html
+/// [2]: http://webserver2.tecgraf.puc-rio.br/iup/en/attrib/iup_font.html
+pub struct FontDlg(*mut iup_sys::Ihandle);
+
+impl FontDlg {
+    /// Creates a font selection dialog.
+    pub fn new() -> FontDlg {
+        unsafe { FontDlg::from_raw(iup_sys::IupFontDlg()) }
+    }
+
+    /// Sets the initial font of the dialog, in the IUP font format.
+    pub fn set_font<S: Into<String>>(&mut self, font: S) -> Self {
+        self.set_attrib("VALUE", font)
+    }
+
+    /// Gets the font selected in the dialog, in the IUP font format.
+    pub fn font(&self) -> Option<String> {
+        self.attrib("VALUE")
+    }
+
+    /// Sets the text shown in the font preview area.
+    pub fn set_preview_text<S: Into<String>>(&mut self, text: S) -> Self {
+        self.set_attrib("PREVIEWTEXT", text)
+    }
+
+    /// Shows the dialog modally, centered to the parent dialog if any, and returns the
+    /// selected font or `None` if the user cancelled the dialog.
+    pub fn open(&mut self, parent: Option<Dialog>) -> Option<String> {
+        if let Some(parent) = parent {
+            self.set_attrib_handle("PARENTDIALOG", parent);
+        }
+        match self.popup(DialogPos::CenterParent, DialogPos::CenterParent) {
+            Ok(_) if self.attrib("STATUS").map(|s| s == "1").unwrap_or(false) => self.font(),
+            _ => None,
+        }
+    }
+}
+
+impl Default for FontDlg {
+    fn default() -> FontDlg {
+        FontDlg::new()
+    }
+}
+
+impl_dialog!(FontDlg, "fontdlg");
+impl ::callback::HelpCb for FontDlg {}
+impl ::attribute::TitleAttribute for FontDlg {}
@@ -0,0 +1,55 @@
+use iup_sys;
+use libc::{c_char, c_int};
+use std::ptr;
+use std::ffi::CString;
+
+/// The result of `list_dialog`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ListDialogResult {
+    /// The indices (starting at 0) of the selected items, in ascending order.
+    Ok(Vec<usize>),
+    /// The user cancelled the dialog.
+    Cancel,
+}
+
+/// Shows a modal dialog for selecting items from a list.
+///
+/// If `max_selections` is 1 only a single item can be selected. Otherwise the list allows
+/// multiple selection and `max_selections` is only a hint: IUP cannot limit the number of
+/// marked items, so every marked item is returned.
+///
+/// See the [IUP ListDialog Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iuplistdialog.html
+pub fn list_dialog<S: Into<String>>(title: S, items: &[&str], max_selections: usize) -> ListDialogResult {
+    let ctitle = CString::new(title.into()).unwrap();
+    let citems: Vec<CString> = items.iter().map(|&s| CString::new(s).unwrap()).collect();
+    let mut list: Vec<*const c_char> = citems.iter().map(|cs| cs.as_ptr()).collect();
+    list.push(ptr::null());
+
+    let max_col = items.iter().map(|s| s.chars().count()).max().unwrap_or(0).max(1);
+    let max_lin = items.len().clamp(1, 10);
+
+    if max_selections == 1 {
+        let selected = unsafe {
+            iup_sys::IupListDialog(1, ctitle.as_ptr(), items.len() as c_int, list.as_mut_ptr(),
+                                   1, max_col as c_int, max_lin as c_int, ptr::null_mut())
+        };
+        match selected {
+            n if n < 0 => ListDialogResult::Cancel,
+            n => ListDialogResult::Ok(vec![n as usize]),
+        }
+    } else {
+        let mut marks: Vec<c_int> = vec![0; items.len()];
+        let status = unsafe {
+            iup_sys::IupListDialog(2, ctitle.as_ptr(), items.len() as c_int, list.as_mut_ptr(),
+                                   0, max_col as c_int, max_lin as c_int, marks.as_mut_ptr())
+        };
+        match status {
+            n if n < 0 => ListDialogResult::Cancel,
+            _ => ListDialogResult::Ok(marks.iter().enumerate()
+                                           .filter(|&(_, &m)| m != 0)
+                                           .map(|(i, _)| i)
+                                           .collect()),
+        }
+    }
+}
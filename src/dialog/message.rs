@@ -2,6 +2,7 @@ use iup_sys;
 use std::ffi::CString;
 
 use Element;
+use dialog::{Dialog, DialogElement, DialogPos};
 
 /// Shows a modal dialog containing a message. It simply creates and popup a `MessageDlg`.
 pub fn message<S1, S2>(title: S1, message: S2)
@@ -27,3 +28,89 @@ impl MessageDlg {
 
 impl_dialog!(MessageDlg, "messagedlg");
 impl ::callback::HelpCb for MessageDlg {}
+impl ::attribute::TitleAttribute for MessageDlg {}
+
+/// The buttons of a `question` dialog.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum QuestionButtons {
+    OkCancel,
+    YesNo,
+    YesNoCancel,
+    RetryCancel,
+}
+
+/// The button pressed in a `question` dialog.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum QuestionResult {
+    Ok,
+    Yes,
+    No,
+    Cancel,
+    Retry,
+}
+
+/// Popups a `MessageDlg` of the specified type and returns the BUTTONRESPONSE.
+fn popup_message(parent: Option<Dialog>, dialog_type: &str, buttons: &str,
+                 title: &str, message: &str) -> Option<u32> {
+    let mut dlg = MessageDlg::new();
+    dlg.set_attrib("DIALOGTYPE", dialog_type);
+    dlg.set_attrib("BUTTONS", buttons);
+    dlg.set_attrib("TITLE", title);
+    dlg.set_attrib("VALUE", message);
+    if let Some(parent) = parent {
+        dlg.set_attrib_handle("PARENTDIALOG", parent);
+    }
+    let response = match dlg.popup(DialogPos::CenterParent, DialogPos::CenterParent) {
+        Ok(_) => dlg.attrib_parse("BUTTONRESPONSE"),
+        Err(_) => None,
+    };
+    dlg.destroy();
+    response
+}
+
+/// Shows a modal information message with an OK button.
+///
+/// The dialog is centered on the `parent` dialog, which becomes inactive while the message is
+/// shown. A `None` parent is fine when there's no dialog shown yet (e.g. before the main
+/// dialog is created), the message is then centered on the screen.
+pub fn info(parent: Option<Dialog>, title: &str, message: &str) {
+    popup_message(parent, "INFORMATION", "OK", title, message);
+}
+
+/// Shows a modal warning message with an OK button.
+///
+/// See `info` for the meaning of `parent`.
+pub fn warning(parent: Option<Dialog>, title: &str, message: &str) {
+    popup_message(parent, "WARNING", "OK", title, message);
+}
+
+/// Shows a modal error message with an OK button.
+///
+/// See `info` for the meaning of `parent`.
+pub fn error(parent: Option<Dialog>, title: &str, message: &str) {
+    popup_message(parent, "ERROR", "OK", title, message);
+}
+
+/// Shows a modal question with the specified buttons and returns the pressed one.
+///
+/// Closing the dialog without pressing a button is the same as pressing its last button.
+///
+/// See `info` for the meaning of `parent`.
+pub fn question(parent: Option<Dialog>, title: &str, message: &str,
+                buttons: QuestionButtons) -> QuestionResult {
+    use self::QuestionButtons::*;
+    let raw_buttons = match buttons {
+        OkCancel => "OKCANCEL",
+        YesNo => "YESNO",
+        YesNoCancel => "YESNOCANCEL",
+        RetryCancel => "RETRYCANCEL",
+    };
+    let response = popup_message(parent, "QUESTION", raw_buttons, title, message);
+    match (buttons, response) {
+        (OkCancel, Some(1)) => QuestionResult::Ok,
+        (YesNo, Some(1)) | (YesNoCancel, Some(1)) => QuestionResult::Yes,
+        (YesNo, _) | (YesNoCancel, Some(2)) => QuestionResult::No,
+        (RetryCancel, Some(1)) => QuestionResult::Retry,
+        _ => QuestionResult::Cancel,
+    }
+}
@@ -18,11 +18,21 @@ pub mod dialog;
 pub mod alarm;
 pub mod message;
 pub mod file;
+pub mod color;
+pub mod font;
+pub mod param;
+pub mod text;
+pub mod list;
 
-pub use self::dialog::{Dialog, ShowState, CopyDataCb, MdiActivateCb, ShowCb, TrayClickCb};
+pub use self::dialog::{Dialog, DialogBuilder, ShowState, ZOrder, Placement, CopyDataCb, MdiActivateCb, ShowCb, TrayClickCb};
 pub use self::alarm::{AlarmButton, alarm};
 pub use self::message::{MessageDlg, message};
-pub use self::file::{FileDlg};
+pub use self::file::{FileDlg, FileDlgType, FileDlgResult};
+pub use self::color::{ColorDlg, prompt_color};
+pub use self::font::FontDlg;
+pub use self::param::{Param, ParamValue, get_params};
+pub use self::text::prompt_text;
+pub use self::list::{ListDialogResult, list_dialog};
 
 // An dialog is a top-level container.
 pub trait DialogElement : Element + Widget + Container {
@@ -115,13 +125,15 @@ impl DialogPos {
         }
     }
 
-    fn to_raw_x(&self) -> c_int {
+    #[doc(hidden)]
+    pub fn to_raw_x(&self) -> c_int {
     	use self::DialogPos::*;
     	assert!(*self != Top && *self != Bottom);
     	self.to_raw()
     }
 
-    fn to_raw_y(&self) -> c_int {
+    #[doc(hidden)]
+    pub fn to_raw_y(&self) -> c_int {
     	use self::DialogPos::*;
     	assert!(*self != Right && *self != Left);
     	self.to_raw()
@@ -0,0 +1,203 @@
+use iup_sys;
+use libc::{c_char, c_int, c_float, c_void};
+use std::ffi::{CStr, CString};
+use std::path::PathBuf;
+use std::ptr;
+
+use attribute::Color;
+
+/// The size of the buffers used for the string like parameters of `get_params`.
+const PARAM_BUFFER_SIZE: usize = 10240;
+
+/// A parameter to be requested by `get_params`.
+///
+/// The first field of each variant is the label shown to the user and the last ones are the
+/// initial value of the parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Param {
+    /// A boolean shown as a toggle.
+    Bool(String, bool),
+    /// An integer shown as a spin text box.
+    Int(String, i32),
+    /// A real number shown as a text box.
+    Float(String, f32),
+    /// A single line text.
+    String(String, String),
+    /// A file name text box with a button to open a file selection dialog.
+    File(String, PathBuf),
+    /// A color shown with a button to open a color selection dialog.
+    Color(String, Color),
+    /// A font shown with a button to open a font selection dialog.
+    Font(String, String),
+    /// A dropdown list of items with the index (starts from 0) of the initially selected item.
+    List(String, Vec<String>, usize),
+}
+
+/// The value of a parameter returned by `get_params`, in the same order of the `Param`s.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamValue {
+    Bool(bool),
+    Int(i32),
+    Float(f32),
+    String(String),
+    File(PathBuf),
+    Color(Color),
+    Font(String),
+    /// The index (starts from 0) of the selected item.
+    List(usize),
+}
+
+/// The memory IUP writes the parameter values to.
+enum ParamData {
+    Int(Box<c_int>),
+    Float(Box<c_float>),
+    Buffer(Vec<u8>),
+}
+
+impl ParamData {
+    fn buffer(value: &str) -> ParamData {
+        assert!(value.len() < PARAM_BUFFER_SIZE);
+        let mut buffer = vec![0u8; PARAM_BUFFER_SIZE];
+        buffer[..value.len()].copy_from_slice(value.as_bytes());
+        ParamData::Buffer(buffer)
+    }
+
+    fn as_ptr(&mut self) -> *mut c_void {
+        match *self {
+            ParamData::Int(ref mut i) => &mut **i as *mut c_int as *mut c_void,
+            ParamData::Float(ref mut f) => &mut **f as *mut c_float as *mut c_void,
+            ParamData::Buffer(ref mut buffer) => buffer.as_mut_ptr() as *mut c_void,
+        }
+    }
+
+    fn int(&self) -> c_int {
+        match *self {
+            ParamData::Int(ref i) => **i,
+            _ => unreachable!(),
+        }
+    }
+
+    fn float(&self) -> c_float {
+        match *self {
+            ParamData::Float(ref f) => **f,
+            _ => unreachable!(),
+        }
+    }
+
+    fn string(&self) -> String {
+        match *self {
+            ParamData::Buffer(ref buffer) => {
+                let cstr = unsafe { CStr::from_ptr(buffer.as_ptr() as *const c_char) };
+                cstr.to_string_lossy().into_owned()
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Accepts every change made in the parameters dialog.
+extern "C" fn param_action(_dialog: *mut iup_sys::Ihandle, _param_index: c_int,
+                       _user_data: *mut c_void) -> c_int {
+    1
+}
+
+/// Shows a modal dialog for capturing parameter values using a generated layout with a
+/// label and an edition element for each parameter.
+///
+/// Returns the values of the parameters if the user confirmed the dialog or `None` if the
+/// user cancelled it.
+///
+/// See the [IUP GetParam Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupgetparam.html
+///
+/// # Panics
+/// Panics if a label or list item contains a `%`, `|` or a line break, or if a string like
+/// initial value is larger than 10KB.
+pub fn get_params<S: Into<String>>(title: S, params: &[Param]) -> Option<Vec<ParamValue>> {
+    let check = |s: &str| assert!(!s.contains(&['%', '|', '\n'][..]));
+
+    let mut format = String::new();
+    let mut data = Vec::with_capacity(params.len());
+
+    for param in params {
+        match *param {
+            Param::Bool(ref label, value) => {
+                check(label);
+                format.push_str(&format!("{}%b\n", label));
+                data.push(ParamData::Int(Box::new(value as c_int)));
+            },
+            Param::Int(ref label, value) => {
+                check(label);
+                format.push_str(&format!("{}%i\n", label));
+                data.push(ParamData::Int(Box::new(value)));
+            },
+            Param::Float(ref label, value) => {
+                check(label);
+                format.push_str(&format!("{}%r\n", label));
+                data.push(ParamData::Float(Box::new(value)));
+            },
+            Param::String(ref label, ref value) => {
+                check(label);
+                format.push_str(&format!("{}%s\n", label));
+                data.push(ParamData::buffer(value));
+            },
+            Param::File(ref label, ref value) => {
+                check(label);
+                format.push_str(&format!("{}%f\n", label));
+                data.push(ParamData::buffer(&value.to_string_lossy()));
+            },
+            Param::Color(ref label, value) => {
+                check(label);
+                format.push_str(&format!("{}%c\n", label));
+                data.push(ParamData::buffer(&format!("{} {} {}", value.r, value.g, value.b)));
+            },
+            Param::Font(ref label, ref value) => {
+                check(label);
+                format.push_str(&format!("{}%n\n", label));
+                data.push(ParamData::buffer(value));
+            },
+            Param::List(ref label, ref items, selected) => {
+                check(label);
+                format.push_str(&format!("{}%l|", label));
+                for item in items {
+                    check(item);
+                    format.push_str(&format!("{}|", item));
+                }
+                format.push('\n');
+                data.push(ParamData::Int(Box::new(selected as c_int)));
+            },
+        }
+    }
+
+    let ctitle = CString::new(title.into()).unwrap();
+    let cformat = CString::new(format).unwrap();
+    let mut pointers = data.iter_mut().map(|d| d.as_ptr()).collect::<Vec<_>>();
+
+    let confirmed = unsafe {
+        iup_sys::IupGetParamv(ctitle.as_ptr(), param_action, ptr::null_mut(), cformat.as_ptr(),
+                              params.len() as c_int, 0, pointers.as_mut_ptr())
+    };
+
+    if confirmed == 0 {
+        return None;
+    }
+
+    Some(params.iter().zip(data.iter()).map(|(param, data)| match *param {
+        Param::Bool(..) => ParamValue::Bool(data.int() != 0),
+        Param::Int(..) => ParamValue::Int(data.int()),
+        Param::Float(..) => ParamValue::Float(data.float()),
+        Param::String(..) => ParamValue::String(data.string()),
+        Param::File(..) => ParamValue::File(PathBuf::from(data.string())),
+        Param::Color(_, initial) => {
+            let components = data.string().split_whitespace()
+                                 .filter_map(|c| c.parse().ok())
+                                 .collect::<Vec<u8>>();
+            match components.len() {
+                3 => ParamValue::Color(Color::rgb(components[0], components[1], components[2])),
+                _ => ParamValue::Color(initial),
+            }
+        },
+        Param::Font(..) => ParamValue::Font(data.string()),
+        Param::List(..) => ParamValue::List(data.int() as usize),
+    }).collect())
+}
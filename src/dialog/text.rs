@@ -0,0 +1,37 @@
+use iup_sys;
+use libc::c_char;
+use std::ffi::{CStr, CString};
+
+/// The size of the buffer IUP writes the edited text to.
+const TEXT_BUFFER_SIZE: usize = 10240;
+
+/// Shows a modal dialog for editing a multiline text, initially containing `text`.
+///
+/// Returns the edited text if the user confirmed the dialog, or `None` if the user cancelled
+/// it or if `text` does not fit in the 10KB buffer passed to IUP.
+///
+/// IUP 3.12 `IupGetText` takes no buffer size: the caller's buffer must fit the edited text,
+/// which is copied into it when the dialog is confirmed. The 10KB buffer used here is the same
+/// size IUP reserves for string parameters in `get_params`.
+///
+/// See the [IUP GetText Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/dlg/iupgettext.html
+pub fn prompt_text<S1, S2>(title: S1, text: S2) -> Option<String>
+                                where S1: Into<String>, S2: Into<String> {
+    let text = text.into();
+    if text.len() >= TEXT_BUFFER_SIZE {
+        return None;
+    }
+
+    let ctitle = CString::new(title.into()).unwrap();
+    let mut buffer = vec![0u8; TEXT_BUFFER_SIZE];
+    buffer[..text.len()].copy_from_slice(text.as_bytes());
+
+    match unsafe { iup_sys::IupGetText(ctitle.as_ptr(), buffer.as_mut_ptr() as *mut c_char) } {
+        1 => {
+            let cstr = unsafe { CStr::from_ptr(buffer.as_ptr() as *const c_char) };
+            Some(cstr.to_string_lossy().into_owned())
+        },
+        _ => None,
+    }
+}
@@ -1,6 +1,7 @@
 //! Hierarchy operations on elements.
 use iup_sys;
 use libc::c_int;
+use std::ptr;
 use std::ffi::CString;
 use std::result::Result;
 
@@ -79,6 +80,35 @@ pub trait Container : Node {
     fn child_count(&self) -> usize {
         unsafe { iup_sys::IupGetChildCount(self.raw()) as usize }
     }
+
+    /// Returns an iterator over the children of the element.
+    ///
+    /// The children are yielded in the same order as `Container::child` positions them.
+    fn children(&self) -> ElementIter {
+        ElementIter { parent: self.raw(), last: ptr::null_mut() }
+    }
+}
+
+/// Iterator over the children of a container.
+///
+/// See `Container::children`.
+pub struct ElementIter {
+    parent: *mut iup_sys::Ihandle,
+    last: *mut iup_sys::Ihandle,
+}
+
+impl Iterator for ElementIter {
+    type Item = Handle;
+
+    fn next(&mut self) -> Option<Handle> {
+        match unsafe { iup_sys::IupGetNextChild(self.parent, self.last) } {
+            ptr if ptr.is_null() => None,
+            ptr => {
+                self.last = ptr;
+                Some(Handle::from_raw(ptr))
+            },
+        }
+    }
 }
 
 /// Nodes are elements that can be part of a hierarchical structure.
@@ -101,12 +131,18 @@ pub trait Node : Element {
 
     /// Moves an interface element from one position in the hierarchy tree to another.
     ///
-    /// TODO ref_child NULL doc. See #23.
+    /// The element is inserted in `new_parent` before `ref_child` or appended after the last
+    /// child when `ref_child` is `None`.
+    ///
+    /// The new parent can be in the same dialog or in another one, but the element and
+    /// `new_parent` must be both mapped or both unmapped. The layout is not updated, call
+    /// `Node::refresh` afterwards.
     ///
     /// See `Container::append` for more details on the semantics of this method.
-    fn reparent<E1, E2>(&mut self, new_parent: E1, ref_child: E2) -> Result<Self, Self>
-                where E1: Container, E2: Node {
-        match unsafe { iup_sys::IupReparent(self.raw(), new_parent.raw(), ref_child.raw()) } {
+    fn reparent<E1>(&mut self, new_parent: E1, ref_child: Option<Handle>) -> Result<Self, Self>
+                where E1: Container {
+        let ref_child = ref_child.map_or(ptr::null_mut(), |child| child.raw());
+        match unsafe { iup_sys::IupReparent(self.raw(), new_parent.raw(), ref_child) } {
             iup_sys::IUP_NOERROR => Ok(*self),
             iup_sys::IUP_ERROR => Err(*self),
             _ => unreachable!(),
@@ -4,20 +4,26 @@
 use iup_sys;
 use libc::{c_void, c_char, c_int};
 use std::ptr;
+use std::slice;
 use std::ffi::{CStr, CString};
 use std::result::Result;
 use std::iter::repeat;
 use std::str::FromStr;
+use std::path::Path;
+use std::fs::File;
+use std::io::{Read, Write};
+
+use attribute::Color;
 
 pub mod guard;
 pub use self::guard::Guard;
 
 pub mod hierarchy;
-pub use self::hierarchy::{Container, Node};
+pub use self::hierarchy::{Container, Node, ElementIter};
 
 #[macro_use]
 pub mod widget;
-pub use self::widget::Widget;
+pub use self::widget::{Widget, NativeHandle};
 
 /// Makes a Vec of `Element` trait objects.
 ///
@@ -37,6 +43,27 @@ macro_rules! elements {
 /// picked up manually by looking at the IUP source code or by looking at the result
 /// of `Element::classname`.
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/func/iupgetclassname.html
+///
+/// # Custom Elements
+///
+/// This macro is exported so elements not bound by IUP-Rust *(e.g. controls from additional IUP
+/// libraries or created with `IupCreate`)* can be wrapped the same way the crate does it.
+/// The type must be a tuple struct around the raw handle and both the `iup_sys` crate and the
+/// `Element` trait must be in scope where the macro is used:
+///
+/// ```ignore
+/// #[macro_use] extern crate iup;
+/// extern crate iup_sys;
+///
+/// use iup::Element;
+///
+/// pub struct GLCanvas(*mut iup_sys::Ihandle);
+/// impl_element!(GLCanvas, "glcanvas");
+/// ```
+///
+/// Registering new classes is not possible since `IupRegisterClass` is not part of the public
+/// IUP API.
+#[macro_export]
 macro_rules! impl_element {
     ($ty_path:path, $classname:expr) => {
         impl_element_nofrom!($ty_path, $classname);
@@ -53,6 +80,8 @@ macro_rules! impl_element {
 ///
 /// This is a necessary thing because if we implemented `From<$ty_path> for Handle` here it'd cause
 /// a compilation error during `From<Handle> for Handle`.
+#[doc(hidden)]
+#[macro_export]
 macro_rules! impl_element_nofrom {
     ($ty_path:path, $classname:expr) => {
 
@@ -142,13 +171,32 @@ impl ::callback::UnmapCb for Handle {}
 impl ::callback::GetFocusCb for Handle {}
 /// Note: The wrapped element may not support `KillFocusCb`.
 impl ::callback::KillFocusCb for Handle {}
+/// Note: The wrapped element may not support `KAnyCb`.
+impl ::callback::key::KAnyCb for Handle {}
+/// Note: The wrapped element may not support `KeyActionCb`.
+impl ::callback::key::KeyActionCb for Handle {}
 /// Note: The wrapped element may not support `EnterWindowCb`.
 impl ::callback::EnterWindowCb for Handle {}
 /// Note: The wrapped element may not support `LeaveWindowCb`.
 impl ::callback::LeaveWindowCb for Handle {}
 /// Note: The wrapped element may not support `HelpCb`.
 impl ::callback::HelpCb for Handle {}
-// TODO impl K_ callbacks when it's implemented.
+/// Note: The wrapped element may not support `TitleAttribute`.
+impl ::attribute::TitleAttribute for Handle {}
+/// Note: The wrapped element may not support `BgColorAttribute`.
+impl ::attribute::BgColorAttribute for Handle {}
+/// Note: The wrapped element may not support `FgColorAttribute`.
+impl ::attribute::FgColorAttribute for Handle {}
+/// Note: The wrapped element may not support `SizeAttribute`.
+impl ::attribute::SizeAttribute for Handle {}
+/// Note: The wrapped element may not support `ScrollBarAttribute`.
+impl ::attribute::ScrollBarAttribute for Handle {}
+/// Note: The wrapped element may not support `CursorAttribute`.
+impl ::attribute::CursorAttribute for Handle {}
+/// Note: The wrapped element may not support `TipAttribute`.
+impl ::attribute::TipAttribute for Handle {}
+/// Note: The wrapped element may not support `DropTargetAttribute`.
+impl ::attribute::DropTargetAttribute for Handle {}
 
 
 /// Every IUP object is an `Element`.
@@ -242,6 +290,61 @@ pub trait Element : Sized + Copy + Clone {
         vec.into_iter().take(len as usize).map(|cstr| string_from_cstr!(cstr)).collect()
     }
 
+    /// Prints the name and value of all attributes returned by `Element::attribs` to the
+    /// standard error, useful for debugging layouts.
+    ///
+    /// Values of attributes holding raw data *(see `Element::set_attrib_data`)* are printed as
+    /// if they were strings.
+    fn dump_attribs(&self) {
+        eprintln!("{} attributes:", unsafe { self.classname() }.to_string_lossy());
+        for name in self.attribs() {
+            eprintln!("  {}={}", name, self.attrib(name.clone()).unwrap_or_default());
+        }
+    }
+
+    /// Writes the attributes set in the internal hash table of the element into a file.
+    ///
+    /// The file contains the attributes in the `NAME=value, ...` format of IupGetAttributes.
+    /// Attributes kept only by the native system *(e.g. the current position of a dialog
+    /// moved by the user)* are not saved.
+    ///
+    /// In case of failure returns the I/O error message.
+    fn save_attribs<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let attribs = string_from_cstr!(unsafe { iup_sys::IupGetAttributes(self.raw()) });
+        File::create(path).and_then(|mut file| file.write_all(attribs.as_bytes()))
+                          .map_err(|e| e.to_string())
+    }
+
+    /// Sets the attributes previously written into a file by `Element::save_attribs`.
+    ///
+    /// In case of failure returns the I/O error message.
+    fn load_attribs<P: AsRef<Path>>(&mut self, path: P) -> Result<Self, String> {
+        let mut attribs = String::new();
+        File::open(path).and_then(|mut file| file.read_to_string(&mut attribs))
+                        .map_err(|e| e.to_string())?;
+        let cattribs = CString::new(attribs).map_err(|e| e.to_string())?;
+        unsafe { iup_sys::IupSetAttributes(self.raw(), cattribs.as_ptr()) };
+        Ok(self.clone())
+    }
+
+    /// Copies the attributes of the `source` element into this element.
+    ///
+    /// Only the registered attributes of the class that can be changed are copied (thus not
+    /// read-only attributes, callbacks or custom attributes), both elements should be of the
+    /// same class.
+    fn copy_attribs_from<E: Element>(&mut self, source: &E) -> Self {
+        unsafe { iup_sys::IupCopyClassAttributes(source.raw(), self.raw()) };
+        self.clone()
+    }
+
+    /// Copies the attributes of this element into the `dest` element.
+    ///
+    /// See `Element::copy_attribs_from` for details.
+    fn copy_attribs_to<E: Element>(&self, dest: &mut E) -> Self {
+        unsafe { iup_sys::IupCopyClassAttributes(self.raw(), dest.raw()) };
+        self.clone()
+    }
+
     /// Sets an interface element attribute.
     ///
     /// See also the [IUP Attributes Guide][1].
@@ -270,6 +373,110 @@ pub trait Element : Sized + Copy + Clone {
         }
     }
 
+    /// Gets an attribute made of two integers, such as `"100x50"`, `"10,20"` or `"2:5"`.
+    ///
+    /// Returns `None` unless both integers could be extracted from the attribute.
+    fn attrib_int_pair<S: Into<String>>(&self, name: S) -> Option<(i32, i32)> {
+        let cname = CString::new(name.into()).unwrap();
+        let mut pair = (0, 0);
+        match unsafe { iup_sys::IupGetIntInt(self.raw(), cname.as_ptr(), &mut pair.0, &mut pair.1) } {
+            2 => Some(pair),
+            _ => None,
+        }
+    }
+
+    /// Sets an attribute made of two integers in the `"AxB"` form used by size attributes
+    /// such as RASTERSIZE.
+    ///
+    /// Position attributes such as POSITION use the `"A,B"` form and must be set with
+    /// `Element::set_attrib` instead.
+    fn set_attrib_int_pair<S: Into<String>>(&mut self, name: S, a: i32, b: i32) -> Self {
+        self.set_attrib(name, format!("{}x{}", a, b))
+    }
+
+    /// Sets an indexed interface element attribute, such as the items of a list.
+    ///
+    /// The attribute name is the concatenation of `name` and `id`, an empty `name` can be used for
+    /// attributes that are only a number, thus `set_attrib_id("", 1, "A")` sets the `"1"` attribute.
+    fn set_attrib_id<S1, S2>(&mut self, name: S1, id: i32, value: S2) -> Self
+                                        where S1: Into<String>, S2: Into<String> {
+        let cname = CString::new(name.into()).unwrap();
+        let cvalue = CString::new(value.into()).unwrap();
+        unsafe { iup_sys::IupSetStrAttributeId(self.raw(), cname.as_ptr(), id, cvalue.as_ptr()) };
+        self.clone()
+    }
+
+    /// Gets an indexed interface element attribute.
+    fn attrib_id<S: Into<String>>(&self, name: S, id: i32) -> Option<String> {
+        let cname = CString::new(name.into()).unwrap();
+        match unsafe { iup_sys::IupGetAttributeId(self.raw(), cname.as_ptr(), id) } {
+            cvalue if cvalue.is_null() => None,
+            cvalue => Some(string_from_cstr!(cvalue)),
+        }
+    }
+
+    /// Sets a two dimensional indexed interface element attribute, such as the cells of a matrix.
+    ///
+    /// The attribute name is the concatenation of `name`, `lin`, `:` and `col`.
+    fn set_attrib_id2<S1, S2>(&mut self, name: S1, lin: i32, col: i32, value: S2) -> Self
+                                        where S1: Into<String>, S2: Into<String> {
+        let cname = CString::new(name.into()).unwrap();
+        let cvalue = CString::new(value.into()).unwrap();
+        unsafe {
+            iup_sys::IupSetStrAttributeId2(self.raw(), cname.as_ptr(), lin, col, cvalue.as_ptr())
+        };
+        self.clone()
+    }
+
+    /// Gets a two dimensional indexed interface element attribute.
+    fn attrib_id2<S: Into<String>>(&self, name: S, lin: i32, col: i32) -> Option<String> {
+        let cname = CString::new(name.into()).unwrap();
+        match unsafe { iup_sys::IupGetAttributeId2(self.raw(), cname.as_ptr(), lin, col) } {
+            cvalue if cvalue.is_null() => None,
+            cvalue => Some(string_from_cstr!(cvalue)),
+        }
+    }
+
+    /// Sets an integer interface element attribute.
+    fn set_attrib_int<S: Into<String>>(&mut self, name: S, value: i32) -> Self {
+        let cname = CString::new(name.into()).unwrap();
+        unsafe { iup_sys::IupSetInt(self.raw(), cname.as_ptr(), value) };
+        self.clone()
+    }
+
+    /// Gets an integer interface element attribute.
+    ///
+    /// Returns `None` if the attribute is not set. A set attribute that is not a valid integer
+    /// is returned as zero.
+    fn attrib_int<S: Into<String>>(&self, name: S) -> Option<i32> {
+        let cname = CString::new(name.into()).unwrap();
+        if self.does_attrib_exist(&cname) {
+            Some(unsafe { iup_sys::IupGetInt(self.raw(), cname.as_ptr()) })
+        } else {
+            None
+        }
+    }
+
+    /// Sets a floating point interface element attribute.
+    fn set_attrib_float<S: Into<String>>(&mut self, name: S, value: f32) -> Self {
+        let cname = CString::new(name.into()).unwrap();
+        unsafe { iup_sys::IupSetFloat(self.raw(), cname.as_ptr(), value) };
+        self.clone()
+    }
+
+    /// Gets a floating point interface element attribute.
+    ///
+    /// Returns `None` if the attribute is not set. A set attribute that is not a valid number
+    /// is returned as zero.
+    fn attrib_float<S: Into<String>>(&self, name: S) -> Option<f32> {
+        let cname = CString::new(name.into()).unwrap();
+        if self.does_attrib_exist(&cname) {
+            Some(unsafe { iup_sys::IupGetFloat(self.raw(), cname.as_ptr()) })
+        } else {
+            None
+        }
+    }
+
     /// Sets a raw interface element attribute.
     ///
     /// # Safety
@@ -290,20 +497,65 @@ pub trait Element : Sized + Copy + Clone {
         unsafe { iup_sys::IupGetAttribute(self.raw(), cname.as_ptr()) as *mut c_void }
     }
 
+    /// Sets a binary interface element attribute, which may contain null bytes.
+    ///
+    /// Only custom attributes (names not known by the element class) keep binary data, for those
+    /// IUP stores the `data` pointer itself instead of copying the bytes.
+    ///
+    /// # Safety
+    /// `data` must stay valid for as long as the attribute holds it. `name` must be a custom
+    /// attribute: predefined attributes such as TITLE or VALUE are read as C strings, reading past
+    /// the end of a slice that contains no null byte, and the behaviour is undefined.
+    unsafe fn set_attrib_bytes<S1>(&mut self, name: S1, data: &[u8]) -> Self
+                                                            where S1: Into<String> {
+        self.set_attrib_data(name, data.as_ptr() as *const c_void)
+    }
+
+    /// Gets `len` bytes of a binary interface element attribute.
+    ///
+    /// Returns `None` if the attribute is not set.
+    ///
+    /// # Safety
+    /// IUP does not know the length of the data, the value of the attribute must point to at
+    /// least `len` readable bytes (such as a value set by `Element::set_attrib_bytes` with the
+    /// same length), otherwise the behaviour is undefined.
+    unsafe fn attrib_bytes<S1>(&self, name: S1, len: usize) -> Option<Vec<u8>>
+                                                            where S1: Into<String> {
+        let cname = CString::new(name.into()).unwrap();
+        match iup_sys::IupGetAttribute(self.raw(), cname.as_ptr()) {
+            ptr if ptr.is_null() => None,
+            ptr => Some(slice::from_raw_parts(ptr as *const u8, len).to_vec()),
+        }
+    }
+
     /// Associates a element with an attribute.
     ///
     /// Instead of using `Element::add_handle_name` and `Element::set_attrib` with a new creative
     /// name, this function automatically creates a non conflict handle name and associates that
     /// with the attribute.
-     fn set_attrib_handle<S1, E>(&mut self, name: S1, elem: E) -> Self
+    ///
+    /// This is the way to assign images, menus and other resources to elements.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let img = ImageRgb::with(pixels![
+    ///     [(255, 0, 0), (0, 255, 0)],
+    ///     [(0, 0, 255), (0, 0, 0)],
+    /// ]);
+    /// let button = Button::new().set_attrib_handle("IMAGE", img);
+    /// assert_eq!(button.attrib_handle("IMAGE").map(|h| h.raw()), Some(img.raw()));
+    /// ```
+    fn set_attrib_handle<S1, E>(&mut self, name: S1, elem: E) -> Self
                                                 where S1: Into<String>, E: Element {
         let cname = CString::new(name.into()).unwrap();
         unsafe { iup_sys::IupSetAttributeHandle(self.raw(), cname.as_ptr(), elem.raw()) };
         self.clone()
-    }   
+    }
 
     /// Gets the handle associated with an attribute.
-    fn attrib_handle<S1>(&mut self, name: S1) -> Option<Handle>
+    ///
+    /// Returns `None` if no element is associated with the attribute.
+    fn attrib_handle<S1>(&self, name: S1) -> Option<Handle>
                                     where S1: Into<String> {
         let cname = CString::new(name.into()).unwrap();
         match unsafe { iup_sys::IupGetAttributeHandle(self.raw(), cname.as_ptr()) } {
@@ -372,7 +624,87 @@ pub trait Element : Sized + Copy + Clone {
         }
     }
 
+    /// Sets the interface element that will receive the keyboard focus.
+    ///
+    /// The element must be mapped and active to receive the focus. The `GetFocusCb` and
+    /// `KillFocusCb` callbacks are invoked as if the user moved the focus.
+    fn set_focus(&mut self) -> Self {
+        unsafe { iup_sys::IupSetFocus(self.raw()) };
+        self.clone()
+    }
 
+    /// Checks whether this element currently has the keyboard focus.
+    fn has_focus(&self) -> bool {
+        unsafe { iup_sys::IupGetFocus() == self.raw() }
+    }
+
+    /// Moves the keyboard focus to the element after this one that can have the focus.
+    ///
+    /// This follows the same order as pressing Tab in the dialog.
+    ///
+    /// Returns the element that received the focus or `None` if failed.
+    fn next_field(&self) -> Option<Handle> {
+        match unsafe { iup_sys::IupNextField(self.raw()) } {
+            ptr if ptr.is_null() => None,
+            ptr => Some(Handle::from_raw(ptr)),
+        }
+    }
+
+    /// Moves the keyboard focus to the element before this one that can have the focus.
+    ///
+    /// This follows the same order as pressing Shift+Tab in the dialog.
+    ///
+    /// Returns the element that received the focus or `None` if failed.
+    fn prev_field(&self) -> Option<Handle> {
+        match unsafe { iup_sys::IupPreviousField(self.raw()) } {
+            ptr if ptr.is_null() => None,
+            ptr => Some(Handle::from_raw(ptr)),
+        }
+    }
+
+    /// Sets a color interface element attribute.
+    ///
+    /// The alpha of the color is ignored, since IUP color attributes are `"r g b"` strings.
+    fn set_attrib_color<S1, C>(&mut self, name: S1, color: C) -> Self
+                                            where S1: Into<String>, C: Into<Color> {
+        let color = color.into();
+        self.set_attrib_rgb(name, (color.r, color.g, color.b))
+    }
+
+    /// Gets a color interface element attribute.
+    ///
+    /// Returns `None` if the attribute is not set. The alpha of the color is always `255`.
+    fn attrib_color<S1>(&self, name: S1) -> Option<Color>
+                                       where S1: Into<String> {
+        self.attrib_rgb(name).map(Color::from)
+    }
+
+    /// Sets an indexed color interface element attribute, such as the color of a list item.
+    ///
+    /// See `Element::set_attrib_id` for how the attribute name is built.
+    fn set_attrib_color_id<S1, C>(&mut self, name: S1, id: i32, color: C) -> Self
+                                            where S1: Into<String>, C: Into<Color> {
+        let cname = CString::new(name.into()).unwrap();
+        let color = color.into();
+        unsafe { iup_sys::IupSetRGBId(self.raw(), cname.as_ptr(), id, color.r, color.g, color.b) };
+        self.clone()
+    }
+
+    /// Gets an indexed color interface element attribute.
+    fn attrib_color_id<S1>(&self, name: S1, id: i32) -> Option<Color>
+                                       where S1: Into<String> {
+        let cname = CString::new(name.into()).unwrap();
+        unsafe {
+            if iup_sys::IupGetAttributeId(self.raw(), cname.as_ptr(), id).is_null() {
+                None
+            } else {
+                let mut rgb = (0u8, 0u8, 0u8);
+                iup_sys::IupGetRGBId(self.raw(), cname.as_ptr(), id,
+                                     &mut rgb.0, &mut rgb.1, &mut rgb.2);
+                Some(Color::from(rgb))
+            }
+        }
+    }
 
     #[doc(hidden)]
     fn set_attrib_rgb<S1>(&mut self, name: S1, rgb: (u8, u8, u8)) -> Self
@@ -475,6 +807,17 @@ pub fn global_data<S1>(name: S1) -> *mut c_void
     unsafe { iup_sys::IupGetGlobal(cname.as_ptr()) as *mut c_void }
 }
 
+/// Returns the names of every element associated with a handle name (with
+/// `Element::add_handle_name` or LED).
+///
+/// Use `Handle::from_named` to retrieve each of those elements back.
+pub fn handle_names() -> Vec<String> {
+    let max = unsafe { iup_sys::IupGetAllNames(ptr::null_mut(), 0) };
+    let mut vec: Vec<*mut c_char> = vec![ptr::null_mut(); max as usize];
+    let len = unsafe { iup_sys::IupGetAllNames(vec.as_mut_ptr(), max) };
+    vec.into_iter().take(len as usize).map(|cstr| string_from_cstr!(cstr)).collect()
+}
+
 
 /// Called whenever a Element gets destroyed.
 ///
@@ -1,5 +1,6 @@
 //! Graphical user interface commons.
 use iup_sys;
+use libc::c_void;
 use std::result::Result;
 
 use element::{Element, Node};
@@ -9,6 +10,7 @@ macro_rules! impl_widget {
         impl_element!($ty_path, $classname);
         impl $crate::element::Widget for $ty_path {}
         impl $crate::element::Node for $ty_path {}
+        impl $crate::attribute::SizeAttribute for $ty_path {}
     }
 }
 
@@ -20,6 +22,17 @@ macro_rules! impl_widget_container {
 }
 
 
+/// The native window of a widget as returned by `Widget::native_handle`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NativeHandle {
+    /// A Windows `HWND`.
+    Win32(*mut c_void),
+    /// A X11 `Window` id.
+    X11(u64),
+    /// The element is not mapped or the driver has no native window for it.
+    Unknown,
+}
+
 // An widget is any element that maps to the graphical user interface.
 pub trait Widget : Element + Node {
     /// Creates (maps) the native interface objects corresponding to the given IUP interface elements. 
@@ -79,4 +92,47 @@ pub trait Widget : Element + Node {
         unsafe { iup_sys::IupHide(self.raw()) };
         self.clone()
     }
+
+    /// Gets the natural size of the element in pixels as a (width, height) pair.
+    ///
+    /// This is the size the element would have if nothing else constrained it, computed
+    /// during the layout (the read-only NATURALSIZE attribute).
+    fn natural_size(&self) -> (u32, u32) {
+        self.attrib_int_pair("NATURALSIZE").map(|(w, h)| (w as u32, h as u32)).unwrap_or((0, 0))
+    }
+
+    /// Gets the current size of the element in pixels as a (width, height) pair.
+    ///
+    /// This is the size assigned by the layout (the read-only CURRENTSIZE attribute), use
+    /// `SizeAttribute::set_rastersize` to request a different size.
+    fn current_size(&self) -> (u32, u32) {
+        self.attrib_int_pair("CURRENTSIZE").map(|(w, h)| (w as u32, h as u32)).unwrap_or((0, 0))
+    }
+
+    /// Gets the position of the element relative to the origin of the main screen in pixels
+    /// as a (x, y) pair.
+    ///
+    /// This is the read-only SCREENPOSITION attribute, only meaningful when the element is mapped.
+    fn screen_position(&self) -> (i32, i32) {
+        self.attrib_int_pair("SCREENPOSITION").unwrap_or((0, 0))
+    }
+
+    /// Gets the native window of the element, only available when the element is mapped.
+    ///
+    /// This is the HWND attribute on Windows and the XWINDOW attribute on the X11 based
+    /// drivers (GTK and Motif).
+    fn native_handle(&self) -> NativeHandle {
+        let mut elem = *self;
+        #[cfg(windows)]
+        let handle = match elem.attrib_data("HWND") {
+            ptr if ptr.is_null() => NativeHandle::Unknown,
+            ptr => NativeHandle::Win32(ptr),
+        };
+        #[cfg(not(windows))]
+        let handle = match elem.attrib_data("XWINDOW") {
+            ptr if ptr.is_null() => NativeHandle::Unknown,
+            ptr => NativeHandle::X11(ptr as u64),
+        };
+        handle
+    }
 }
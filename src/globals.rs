@@ -0,0 +1,84 @@
+//! Global attributes of the IUP toolkit.
+//!
+//! Global attributes are those not associated with any element, such as the current driver,
+//! the screen properties or the language of the predefined dialogs. See the
+//! [global attributes][1] documentation for the full list.
+//!
+//! Every function in this module must be called within the bounds of `with_iup`.
+//!
+//! [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/attrib/iup_globals.html
+use iup_sys;
+use std::ptr;
+use std::ffi::CString;
+
+/// Panics if IUP is not initialized.
+fn assert_open() {
+    assert!(::is_open(), "IUP global attributes must be used only after IUP is initialized");
+}
+
+/// Sets an attribute in the global environment.
+///
+/// # Panics
+/// Panics if IUP is not initialized.
+pub fn set(name: &str, value: &str) {
+    assert_open();
+    let cname = CString::new(name).unwrap();
+    let cvalue = CString::new(value).unwrap();
+    unsafe { iup_sys::IupSetStrGlobal(cname.as_ptr(), cvalue.as_ptr()) };
+}
+
+/// Gets an attribute from the global environment.
+///
+/// # Panics
+/// Panics if IUP is not initialized.
+pub fn get(name: &str) -> Option<String> {
+    assert_open();
+    let cname = CString::new(name).unwrap();
+    match unsafe { iup_sys::IupGetGlobal(cname.as_ptr()) } {
+        cvalue if cvalue.is_null() => None,
+        cvalue => Some(string_from_cstr!(cvalue)),
+    }
+}
+
+/// Gets a global attribute made of two integers, such as `"800x600"`.
+///
+/// A null handle makes `IupGetIntInt` read the global environment.
+fn get_int_pair(name: &str) -> Option<(i32, i32)> {
+    assert_open();
+    let cname = CString::new(name).unwrap();
+    let mut pair = (0, 0);
+    match unsafe { iup_sys::IupGetIntInt(ptr::null_mut(), cname.as_ptr(), &mut pair.0, &mut pair.1) } {
+        2 => Some(pair),
+        _ => None,
+    }
+}
+
+/// Gets the full screen size in pixels as a (width, height) pair.
+///
+/// This is the SCREENSIZE global attribute.
+///
+/// # Panics
+/// Panics if IUP is not initialized.
+pub fn screen_size() -> (u32, u32) {
+    get_int_pair("SCREENSIZE").map(|(w, h)| (w as u32, h as u32)).unwrap_or((0, 0))
+}
+
+/// Gets the screen depth in bits per pixel.
+///
+/// This is the SCREENDEPTH global attribute.
+///
+/// # Panics
+/// Panics if IUP is not initialized.
+pub fn screen_depth() -> u32 {
+    get("SCREENDEPTH").and_then(|s| s.parse().ok()).unwrap_or(0)
+}
+
+/// Gets the name of the driver in use, such as `"GTK"`, `"Motif"` or `"Win32"`.
+///
+/// This is the DRIVER global attribute.
+///
+/// # Panics
+/// Panics if IUP is not initialized.
+pub fn driver() -> String {
+    get("DRIVER").unwrap_or_default()
+}
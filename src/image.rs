@@ -1,6 +1,29 @@
 //! Image elements to be embedded in other controls.
+//!
+//! # The `image` feature
+//!
+//! When the `image` feature is enabled, buffers from the [`image`][1] crate (version 0.24 or
+//! later) can be converted into `ImageRgb` and `ImageRgba` elements with `From`:
+//!
+//! ```ignore
+//! let picture = image::open("picture.png").unwrap();
+//! let img = ImageRgba::from(&picture);
+//! ```
+//!
+//! [1]: https://crates.io/crates/image
 use iup_sys;
 use Element;
+use Handle;
+
+/// Number of elements in a `width` by `height` image with `channels` elements per pixel.
+///
+/// # Panics
+/// Panics if the number overflows a `usize`.
+fn buffer_len(width: u32, height: u32, channels: usize) -> usize {
+    (width as usize).checked_mul(height as usize)
+                    .and_then(|n| n.checked_mul(channels))
+                    .expect("image dimensions overflow")
+}
 
 #[derive(Debug, Copy, Clone)]
 pub struct InPixels<'a, T: 'static>(pub &'a [&'a [T]]);
@@ -58,6 +81,22 @@ pub trait ImageElement : Element {
     }
 }
 
+/// Finds the image associated with the specified handle name.
+///
+/// Images are usually referenced by attributes through their handle names (e.g. IMAGE of a
+/// button) which can be given with `Element::add_handle_name` or by LED.
+///
+/// Returns `None` if no element has this name or if it is not an image of type `E`.
+///
+/// # Example
+/// ```ignore
+/// ImageRgb::with(pixels![[(255, 0, 0)]]).add_handle_name("img_red");
+/// let red: Option<ImageRgb> = image::image_by_name("img_red");
+/// ```
+pub fn image_by_name<E: ImageElement>(name: &str) -> Option<E> {
+    Handle::from_named(name).and_then(|handle| handle.try_downcast::<E>().ok())
+}
+
 
 /// A one-channel image which each pixel is a byte index to a pallet of RGB colors.
 /// 
@@ -88,7 +127,7 @@ impl ImageElement for Image {
     fn new<U>(width: u32, height: u32, pixels: U) -> Image where U: AsRef<[u8]> {
         unsafe {
             let pixels = pixels.as_ref();
-            assert_eq!((width * height) as usize, pixels.len());
+            assert_eq!(buffer_len(width, height, 1), pixels.len());
             Image::from_raw(iup_sys::IupImage(width as i32, height as i32, pixels.as_ptr()))
         }
     }
@@ -103,12 +142,25 @@ impl_element!(Image, "image");
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupimage.html
 pub struct ImageRgb(*mut iup_sys::Ihandle);
 
+impl ImageRgb {
+    /// Creates a new image from packed RGB bytes, three bytes per pixel, line by line.
+    ///
+    /// # Panics
+    /// Panics if the length of `bytes` is not `width * height * 3`.
+    pub fn from_bytes(width: u32, height: u32, bytes: &[u8]) -> ImageRgb {
+        assert_eq!(buffer_len(width, height, 3), bytes.len());
+        unsafe {
+            ImageRgb::from_raw(iup_sys::IupImageRGB(width as i32, height as i32, bytes.as_ptr()))
+        }
+    }
+}
+
 impl ImageElement for ImageRgb {
     type Pixel = (u8, u8, u8);
     fn new<U>(width: u32, height: u32, pixels: U) -> ImageRgb where U: AsRef<[(u8, u8, u8)]> {
         unsafe {
             let pixels = pixels.as_ref();
-            assert_eq!((width * height) as usize, pixels.len());
+            assert_eq!(buffer_len(width, height, 1), pixels.len());
             ImageRgb::from_raw(iup_sys::IupImageRGB(width as i32, height as i32,
                                                     pixels.as_ptr() as *const _))
         }
@@ -124,12 +176,25 @@ impl_element!(ImageRgb, "imagergb");
 /// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupimage.html
 pub struct ImageRgba(*mut iup_sys::Ihandle);
 
+impl ImageRgba {
+    /// Creates a new image from packed RGBA bytes, four bytes per pixel, line by line.
+    ///
+    /// # Panics
+    /// Panics if the length of `bytes` is not `width * height * 4`.
+    pub fn from_bytes(width: u32, height: u32, bytes: &[u8]) -> ImageRgba {
+        assert_eq!(buffer_len(width, height, 4), bytes.len());
+        unsafe {
+            ImageRgba::from_raw(iup_sys::IupImageRGBA(width as i32, height as i32, bytes.as_ptr()))
+        }
+    }
+}
+
 impl ImageElement for ImageRgba {
     type Pixel = (u8, u8, u8, u8);
     fn new<U>(width: u32, height: u32, pixels: U) -> ImageRgba where U: AsRef<[(u8, u8, u8, u8)]> {
         unsafe {
             let pixels = pixels.as_ref();
-            assert_eq!((width * height) as usize, pixels.len());
+            assert_eq!(buffer_len(width, height, 1), pixels.len());
             ImageRgba::from_raw(iup_sys::IupImageRGBA(width as i32, height as i32,
                                                      pixels.as_ptr() as *const _))
         }
@@ -137,3 +202,33 @@ impl ImageElement for ImageRgba {
 }
 
 impl_element!(ImageRgba, "imagergba");
+
+
+// Conversions from the `image` crate buffers, enabled by the `image` feature.
+//
+// IUP copies the pixels into its own storage, so borrowing the buffer is enough.
+
+#[cfg(feature = "image")]
+impl<'a> From<&'a ::image_crate::RgbImage> for ImageRgb {
+    fn from(img: &'a ::image_crate::RgbImage) -> ImageRgb {
+        ImageRgb::from_bytes(img.width(), img.height(), img.as_raw())
+    }
+}
+
+#[cfg(feature = "image")]
+impl<'a> From<&'a ::image_crate::RgbaImage> for ImageRgba {
+    fn from(img: &'a ::image_crate::RgbaImage) -> ImageRgba {
+        ImageRgba::from_bytes(img.width(), img.height(), img.as_raw())
+    }
+}
+
+/// Any dynamic image can be converted, non-RGBA8 images are converted to RGBA8 first.
+#[cfg(feature = "image")]
+impl<'a> From<&'a ::image_crate::DynamicImage> for ImageRgba {
+    fn from(img: &'a ::image_crate::DynamicImage) -> ImageRgba {
+        match *img {
+            ::image_crate::DynamicImage::ImageRgba8(ref rgba) => ImageRgba::from(rgba),
+            ref other => ImageRgba::from(&other.to_rgba8()),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use iup_sys;
+
+use Element;
+
+/// A container that can interactively show or hide its child.
+///
+/// See the [IUP Expander Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupexpander.html
+pub struct Expander(*mut iup_sys::Ihandle);
+
+impl Expander {
+    /// Creates an expander with a child element.
+    pub fn new<E: Element>(child: E) -> Expander {
+        unsafe { Expander::from_raw(iup_sys::IupExpander(child.raw())) }
+    }
+
+    /// Shows (open) or hides (close) the child of the expander.
+    pub fn set_open(&mut self, open: bool) -> Self {
+        self.set_attrib("STATE", if open { "OPEN" } else { "CLOSE" })
+    }
+
+    /// Checks whether the child of the expander is shown.
+    pub fn is_open(&self) -> bool {
+        self.attrib("STATE").map(|s| s == "OPEN").unwrap_or(true)
+    }
+}
+
+impl_widget_container!(Expander, "expander");
+impl ::attribute::TitleAttribute for Expander {}
+impl ::attribute::BgColorAttribute for Expander {}
+impl ::attribute::FgColorAttribute for Expander {}
+
+/// Action generated after the expander state is interactively changed.
+impl ::callback::Action for Expander {}
+
+/// See the `OpenCloseCb` documentation.
+impl self::OpenCloseCb for Expander {}
+
+impl_callback! {
+    #[doc="Action generated before the expander state is interactively changed."]
+    #[doc=""]
+    #[doc="The `bool` parameter is the state to be applied, `true` if the expander is being opened."]
+    #[doc=""]
+    #[doc="If `CallbackReturn::Ignore` is returned the state change is aborted."]
+    pub trait OpenCloseCb where Self: Element {
+        let name = "OPENCLOSE_CB";
+        extern fn listener(ih: *mut iup_sys::Ihandle, state: c_int) -> CallbackReturn;
+        fn set_openclose_cb<F: Callback(Self, bool)>(&mut self, cb: F) -> Self;
+        fn remove_openclose_cb(&mut self) -> Option<Box<_>>;
+    }
+}
@@ -16,6 +16,23 @@ impl Fill {
     pub fn new() -> Fill {
         unsafe { Fill::from_raw(iup_sys::IupFill()) }
     }
+
+    /// Creates a fill with a fixed size, in units proportional to the size of a character.
+    ///
+    /// The size applies along the direction of the parent box: it is the width of the fill
+    /// inside an `HBox` and its height inside a `VBox`. Both dimensions are set, so the fill
+    /// works in either box. A fill with a fixed size does not expand.
+    pub fn with_size(size: i32) -> Fill {
+        Fill::new().set_attrib_int_pair("SIZE", size, size)
+    }
+
+    /// Sets the size of the fill in pixels.
+    ///
+    /// This is the RASTERSIZE attribute, the width inside an `HBox` and the height inside
+    /// a `VBox`. As in `Fill::with_size` both dimensions are set.
+    pub fn set_min_size(&mut self, size: i32) -> Self {
+        self.set_attrib_int_pair("RASTERSIZE", size, size)
+    }
 }
 
 impl_widget!(Fill, "fill");
@@ -0,0 +1,82 @@
+use iup_sys;
+
+use Handle;
+use Element;
+use attribute::{HorizontalAlignment, VerticalAlignment};
+
+/// A void container for composing elements in a regular grid.
+///
+/// The children are distributed by lines (or by columns, see `GridBox::set_num_lin`) in the
+/// order they are given. Line and column numbers start from 0.
+///
+/// See the [IUP GridBox Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupgridbox.html
+pub struct GridBox(*mut iup_sys::Ihandle);
+
+impl GridBox {
+    /// Creates a grid container with the specified childs.
+    pub fn new<A>(elems: A) -> GridBox where A: AsRef<[Handle]> {
+        let mut carray = slice_to_ih_array!(elems.as_ref());
+        unsafe { GridBox::from_raw(iup_sys::IupGridBoxv(carray.as_mut_ptr())) }
+    }
+
+    /// Distributes the children by lines with the specified number of columns.
+    pub fn set_num_col(&mut self, n: usize) -> Self {
+        self.set_attrib("ORIENTATION", "HORIZONTAL");
+        self.set_attrib("NUMDIV", n.to_string())
+    }
+
+    /// Distributes the children by columns with the specified number of lines.
+    pub fn set_num_lin(&mut self, n: usize) -> Self {
+        self.set_attrib("ORIENTATION", "VERTICAL");
+        self.set_attrib("NUMDIV", n.to_string())
+    }
+
+    /// Sets the number of divisions (columns or lines depending on the orientation) to be
+    /// calculated automatically from the natural size of the children. This is the default.
+    pub fn set_num_div_auto(&mut self) -> Self {
+        self.set_attrib("NUMDIV", "AUTO")
+    }
+
+    /// Sets the space between lines in pixels.
+    pub fn set_gap_lin(&mut self, gap: i32) -> Self {
+        self.set_attrib("GAPLIN", gap.to_string())
+    }
+
+    /// Sets the space between columns in pixels.
+    pub fn set_gap_col(&mut self, gap: i32) -> Self {
+        self.set_attrib("GAPCOL", gap.to_string())
+    }
+
+    /// Sets the horizontal alignment of the children in every column.
+    pub fn set_alignment_col(&mut self, align: HorizontalAlignment) -> Self {
+        self.set_attrib("ALIGNMENTCOL", align.as_str())
+    }
+
+    /// Sets the vertical alignment of the children in every line.
+    pub fn set_alignment_lin(&mut self, align: VerticalAlignment) -> Self {
+        self.set_attrib("ALIGNMENTLIN", align.as_str())
+    }
+
+    /// Sets the horizontal alignment of the children in the specified column.
+    pub fn set_alignment_col_at(&mut self, col: usize, align: HorizontalAlignment) -> Self {
+        self.set_attrib_id("ALIGNMENTCOL", col as i32, align.as_str())
+    }
+
+    /// Sets the vertical alignment of the children in the specified line.
+    pub fn set_alignment_lin_at(&mut self, lin: usize, align: VerticalAlignment) -> Self {
+        self.set_attrib_id("ALIGNMENTLIN", lin as i32, align.as_str())
+    }
+
+    /// Gets the number of columns after the layout is computed.
+    pub fn num_col(&self) -> usize {
+        self.attrib_parse("NUMCOL").unwrap_or(0)
+    }
+
+    /// Gets the number of lines after the layout is computed.
+    pub fn num_lin(&self) -> usize {
+        self.attrib_parse("NUMLIN").unwrap_or(0)
+    }
+}
+
+impl_widget_container!(GridBox, "gridbox");
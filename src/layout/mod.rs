@@ -6,8 +6,18 @@ pub mod vbox;
 pub mod hbox;
 pub mod radio;
 pub mod fill;
+pub mod split;
+pub mod scrollbox;
+pub mod expander;
+pub mod gridbox;
+pub mod zbox;
 
 pub use self::vbox::VBox;
 pub use self::hbox::HBox;
 pub use self::radio::Radio;
 pub use self::fill::Fill;
+pub use self::split::Split;
+pub use self::scrollbox::ScrollBox;
+pub use self::expander::{Expander, OpenCloseCb};
+pub use self::gridbox::GridBox;
+pub use self::zbox::{ZBox, Alignment};
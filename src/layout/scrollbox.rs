@@ -0,0 +1,44 @@
+use iup_sys;
+
+use Element;
+
+/// A container that allows its child to be scrolled when it is larger than the container.
+///
+/// The scrollbars and the scrolling are fully managed by the container, the scroll position
+/// is in pixels.
+///
+/// See the [IUP ScrollBox Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupscrollbox.html
+pub struct ScrollBox(*mut iup_sys::Ihandle);
+
+impl ScrollBox {
+    /// Creates a scroll box with a child element.
+    pub fn new<E: Element>(child: E) -> ScrollBox {
+        unsafe { ScrollBox::from_raw(iup_sys::IupScrollBox(child.raw())) }
+    }
+
+    /// Gets the horizontal scroll position in pixels.
+    pub fn scroll_x(&self) -> i32 {
+        self.attrib_parse::<f32, _>("POSX").unwrap_or(0.0) as i32
+    }
+
+    /// Gets the vertical scroll position in pixels.
+    pub fn scroll_y(&self) -> i32 {
+        self.attrib_parse::<f32, _>("POSY").unwrap_or(0.0) as i32
+    }
+
+    /// Sets the horizontal scroll position in pixels.
+    pub fn set_scroll_x(&mut self, x: i32) -> Self {
+        self.set_attrib("POSX", x.to_string())
+    }
+
+    /// Sets the vertical scroll position in pixels.
+    pub fn set_scroll_y(&mut self, y: i32) -> Self {
+        self.set_attrib("POSY", y.to_string())
+    }
+}
+
+impl_widget_container!(ScrollBox, "scrollbox");
+impl ::attribute::ScrollBarAttribute for ScrollBox {}
+// The SCROLL_CB, BUTTON_CB, MOTION_CB and RESIZE_CB callbacks are used internally by the
+// scroll box and must not be replaced, so their traits are not implemented.
@@ -0,0 +1,59 @@
+use iup_sys;
+
+use Element;
+use Orientation;
+
+/// A container that splits its area in two parts, one for each child, separated by a movable
+/// bar.
+///
+/// See the [IUP Split Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupsplit.html
+pub struct Split(*mut iup_sys::Ihandle);
+
+impl Split {
+    /// Creates a split container with the bar in the specified orientation.
+    ///
+    /// A vertical bar places the children side by side while a horizontal bar places them one
+    /// above the other.
+    pub fn new<E1: Element, E2: Element>(orient: Orientation, child1: E1, child2: E2) -> Split {
+        let mut split = unsafe { Split::from_raw(iup_sys::IupSplit(child1.raw(), child2.raw())) };
+        split.set_attrib_data("ORIENTATION", orient.as_cstr() as *const _)
+    }
+
+    /// Creates a split container with a horizontal bar, the first child on top of the second.
+    pub fn horizontal<E1: Element, E2: Element>(top: E1, bottom: E2) -> Split {
+        Split::new(Orientation::Horizontal, top, bottom)
+    }
+
+    /// Creates a split container with a vertical bar, the first child at left of the second.
+    pub fn vertical<E1: Element, E2: Element>(left: E1, right: E2) -> Split {
+        Split::new(Orientation::Vertical, left, right)
+    }
+
+    /// Sets the position of the bar as a proportion of the container size in the `[0, 1000]`
+    /// interval. Default is 500, the middle of the container.
+    pub fn set_value(&mut self, value: i32) -> Self {
+        self.set_attrib("VALUE", value.to_string())
+    }
+
+    /// Gets the position of the bar as a proportion of the container size in the `[0, 1000]`
+    /// interval.
+    pub fn value(&self) -> i32 {
+        self.attrib_parse("VALUE").unwrap_or(500)
+    }
+
+    /// Sets the interval the bar position can be moved to, in the same unit as `set_value`.
+    pub fn set_min_max(&mut self, min: i32, max: i32) -> Self {
+        self.set_attrib("MINMAX", format!("{}:{}", min, max))
+    }
+
+    /// Sets whether a child is hidden when the bar is moved to the edge of the container.
+    pub fn set_autohide(&mut self, autohide: bool) -> Self {
+        self.set_attrib("AUTOHIDE", if autohide { "YES" } else { "NO" })
+    }
+}
+
+impl_widget_container!(Split, "split");
+
+/// Called after the value was interactively changed by the user, that is, the bar was moved.
+impl ::callback::ValueChangedCb for Split {}
@@ -1,4 +1,5 @@
 use iup_sys;
+use std::iter::FromIterator;
 
 use Handle;
 use Element;
@@ -16,6 +17,18 @@ impl VBox {
         let mut carray = slice_to_ih_array!(elems.as_ref());
         unsafe { VBox::from_raw(iup_sys::IupVboxv(carray.as_mut_ptr())) }
     }
+
+    /// Creates a vertical container box with the specified childs and `gap` pixels between them.
+    pub fn with_gap<A>(elems: A, gap: i32) -> VBox where A: AsRef<[Handle]> {
+        VBox::new(elems).set_attrib("GAP", gap.to_string())
+    }
+}
+
+/// Creates a vertical container box with the childs yielded by the iterator.
+impl FromIterator<Handle> for VBox {
+    fn from_iter<I: IntoIterator<Item=Handle>>(iter: I) -> VBox {
+        VBox::new(iter.into_iter().collect::<Vec<_>>())
+    }
 }
 
 impl_widget_container!(VBox, "vbox");
@@ -0,0 +1,80 @@
+use iup_sys;
+
+use Handle;
+use Element;
+use element::Container;
+
+/// The alignment of the visible child of a `ZBox`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Alignment {
+    North,
+    South,
+    West,
+    East,
+    NorthEast,
+    SouthEast,
+    NorthWest,
+    SouthWest,
+    Center,
+}
+
+impl Alignment {
+    #[doc(hidden)]
+    pub fn as_str(self) -> &'static str {
+        use self::Alignment::*;
+        match self {
+            North => "NORTH",
+            South => "SOUTH",
+            West => "WEST",
+            East => "EAST",
+            NorthEast => "NE",
+            SouthEast => "SE",
+            NorthWest => "NW",
+            SouthWest => "SW",
+            Center => "ACENTER",
+        }
+    }
+}
+
+/// A void container for composing elements in hidden layers with only one layer visible.
+///
+/// It is a box that piles up the children it contains, only the one child is visible.
+///
+/// See the [IUP ZBox Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupzbox.html
+pub struct ZBox(*mut iup_sys::Ihandle);
+
+impl ZBox {
+    /// Creates a zbox container with the specified childs, the first one is initially visible.
+    pub fn new<A>(elems: A) -> ZBox where A: AsRef<[Handle]> {
+        let mut carray = slice_to_ih_array!(elems.as_ref());
+        unsafe { ZBox::from_raw(iup_sys::IupZboxv(carray.as_mut_ptr())) }
+    }
+
+    /// Makes the specified child the visible one.
+    pub fn set_value<E: Element>(&mut self, child: E) -> Self {
+        self.set_attrib_handle("VALUE", child)
+    }
+
+    /// Gets the visible child or `None` if the zbox has no children.
+    pub fn value(&self) -> Option<Handle> {
+        self.child(self.value_pos())
+    }
+
+    /// Makes the child at the specified position (starts from 0) the visible one.
+    pub fn set_value_pos(&mut self, pos: usize) -> Self {
+        self.set_attrib("VALUEPOS", pos.to_string())
+    }
+
+    /// Gets the position (starts from 0) of the visible child.
+    pub fn value_pos(&self) -> usize {
+        self.attrib_parse("VALUEPOS").unwrap_or(0)
+    }
+
+    /// Sets the alignment of the visible child. Default is `Alignment::NorthWest`.
+    pub fn set_alignment(&mut self, align: Alignment) -> Self {
+        self.set_attrib("ALIGNMENT", align.as_str())
+    }
+}
+
+impl_widget_container!(ZBox, "zbox");
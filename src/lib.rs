@@ -33,14 +33,18 @@
 //!   + The [layout](layout/) submodule contains the abstract layout composition controls.
 //!   + The [dialogs](dialog/) submodule contains the dialog definitions, such as windows,
 //!     message boxes, file selection, color selection between others.
+//!   + The [menus](menu/) submodule contains the menu bars, popup menus and their items.
+//!   + The [globals](globals/) submodule gives access to the global attributes of the toolkit.
+//!   + The [config](config/) submodule stores persistent application settings in files.
+//!   + The [testing](testing/) submodule records and plays back user input.
 //!
 //! Each of those elements communicates with the programmer by the means of [callbacks](callback/)
 //! and attributes. Callbacks are closures that gets called when *something* happens with the
 //! control such as a  button click and attributes are the way to set and get specific properties
 //! of the element such as it's design or value.
 //!
-//! Currently attributes are not individual methods specific to each element but that may be a
-//! thing in the future.
+//! Most attributes are not individual methods specific to each element, but the most common ones
+//! can also be accessed in a typed way through the traits of the [attribute](attribute/) submodule.
 //!
 //! The binding is built in a way one can build controls or even the entire window of the
 //! application in a single expression in a very expressive way, for example:
@@ -104,9 +108,15 @@
 
 extern crate libc;
 extern crate iup_sys;
+#[cfg(feature = "image")]
+extern crate image as image_crate;
 
 use std::result::Result;
 use std::ptr;
+use std::ffi::CString;
+use std::env;
+use libc::{c_char, c_int};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 #[macro_use]
 mod macros;
@@ -118,32 +128,23 @@ pub use element::{Element, Handle, Guard};
 #[macro_use]
 pub mod callback;
 
+pub mod attribute;
 pub mod dialog;
 pub mod layout;
 pub mod control;
+pub mod menu;
 
 pub mod led;
 pub mod image;
 pub mod timer;
 pub mod clipboard;
+pub mod config;
+pub mod testing;
+pub mod globals;
 
 pub mod prelude;
 
-pub enum Orientation {
-    Vertical,
-    Horizontal,
-}
-
-impl Orientation {
-    #[doc(hidden)]
-    pub fn as_cstr(self) -> *const libc::c_char {
-        use self::Orientation::*;
-        match self {
-            Vertical => cstr!("VERTICAL"),
-            Horizontal => cstr!("HORIZONTAL"),
-        }
-    }
-}
+pub use attribute::Orientation;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum InitError {
@@ -170,15 +171,19 @@ pub enum InitError {
 ///
 /// ## Blocking
 /// This functin will not return until until a callback returns `CallbackReturn::Close`,
-/// IupExitLoop (TODO) is called, or there are no visible dialogs. 
+/// `exit_loop` is called, or there are no visible dialogs. 
 ///
 /// If the `f` closure returns successfully without any visible dialogs and no active timers,
 /// the application will hang and will not be possible to close the main loop. The process will
 /// have to be interrupted by the system.
 ///
-/// When the last visible dialog is hidden the IupExitLoop (TODO) function is automatically called,
+/// When the last visible dialog is hidden the `exit_loop` function is automatically called,
 /// causing this function to return. To avoid that set LOCKLOOP=YES before hiding the last dialog.
 ///
+/// ## Version
+/// This binding requires IUP 3.12 or newer (see `MIN_VERSION_NUMBER`). This function panics if
+/// the IUP shared library is older than that.
+///
 /// ## Enviroment Variables
 ///
 /// The toolkit's initialization depends also on platform-dependent environment variables, see
@@ -189,10 +194,37 @@ pub enum InitError {
 ///   + **VERSION**: When this variable is set to `YES`, IUP generates a message dialog indicating
 ///     the driver's version when initializing.  Default: `NO`.
 ///
+/// ## Command Line Arguments
+/// The program arguments are not passed to IUP, use `with_iup_args` for that.
 pub fn with_iup<F: FnOnce() -> Result<(), String>>(f: F) -> Result<(), InitError> {
+    open_and_run(ptr::null(), ptr::null(), f)
+}
+
+/// Same as `with_iup` but also passes the program arguments (from `std::env::args_os`) to IUP.
+///
+/// The arguments are forwarded to the underlying toolkit, which may use them to initialize the
+/// application (e.g. GTK options such as `--display` or the application name on Mac OS X).
+pub fn with_iup_args<F: FnOnce() -> Result<(), String>>(f: F) -> Result<(), InitError> {
+    let args: Vec<CString> = env::args_os().map(|arg| {
+        CString::new(arg.to_string_lossy().into_owned()).unwrap_or_default()
+    }).collect();
+    let mut argv: Vec<*const c_char> = args.iter().map(|arg| arg.as_ptr()).collect();
+    argv.push(ptr::null());
+    let argc = args.len() as c_int;
+    let argv_ptr = argv.as_ptr();
+    // `args` and `argv` live until the application is closed, the toolkit may hold them.
+    open_and_run(&argc, &argv_ptr, f)
+}
+
+fn open_and_run<F>(argc: *const c_int, argv: *const *const *const c_char, f: F) -> Result<(), InitError>
+                                                    where F: FnOnce() -> Result<(), String> {
 
-    match unsafe { iup_sys::IupOpen(ptr::null(), ptr::null()) } {
-        iup_sys::IUP_NOERROR => {},
+    assert!(version_number() >= MIN_VERSION_NUMBER,
+            "IUP-Rust requires IUP 3.12 or newer, but the IUP library is version {}",
+            version());
+
+    match unsafe { iup_sys::IupOpen(argc, argv) } {
+        iup_sys::IUP_NOERROR => IUP_OPEN.store(true, Ordering::SeqCst),
         iup_sys::IUP_OPENED => return Err(InitError::AlreadyOpen),
         iup_sys::IUP_ERROR => return Err(InitError::Error),
         _ => unreachable!(),
@@ -217,17 +249,120 @@ pub fn with_iup<F: FnOnce() -> Result<(), String>>(f: F) -> Result<(), InitError
     // also calls our iup-rust specific close callback.
     callback::remove_idle();
     callback::remove_close_cb().map( |mut fbox| fbox.on_callback(()) );
+    IUP_OPEN.store(false, Ordering::SeqCst);
     unsafe { iup_sys::IupClose(); }
 
     user_result.map_err(|e| InitError::UserError(e))
 }
 
-/// Returns a string with the IUP version number.
+/// The outcome of a `loop_step`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LoopStepResult {
+    /// The events were processed and the application should keep running.
+    Continue,
+    /// A callback returned `CallbackReturn::Close` or `exit_loop` was called.
+    Close,
+}
+
+/// Runs one iteration of the message loop.
+///
+/// This function is useful for allowing a second message loop to be managed by the application
+/// itself, such as in games and simulations. It processes the pending events and returns
+/// without waiting for new ones.
+///
+/// # Thread Safety
+///
+/// Like any other IUP function this is **not** safe to call from a thread other than the one
+/// running `with_iup`.
+pub fn loop_step() -> LoopStepResult {
+    match unsafe { iup_sys::IupLoopStep() } {
+        iup_sys::IUP_CLOSE => LoopStepResult::Close,
+        _ => LoopStepResult::Continue,
+    }
+}
+
+/// Runs the message loop with `loop_step` while `f` returns `true`.
+///
+/// The loop also stops when a `loop_step` returns `LoopStepResult::Close`, in which case this
+/// function returns `LoopStepResult::Close`, otherwise it returns `LoopStepResult::Continue`.
+///
+/// # Example
+/// ```ignore
+/// let mut dialog = Dialog::new(Label::with_title("Closing soon..."));
+/// dialog.show().unwrap();
+/// let mut iterations = 0;
+/// iup::loop_while(|| {
+///     iterations += 1;
+///     iterations <= 5
+/// });
+/// dialog.hide();
+/// ```
+pub fn loop_while<F: FnMut() -> bool>(mut f: F) -> LoopStepResult {
+    while f() {
+        if loop_step() == LoopStepResult::Close {
+            return LoopStepResult::Close;
+        }
+    }
+    LoopStepResult::Continue
+}
+
+/// Processes all pending messages in the message queue.
+///
+/// When you change an attribute of a certain element, the change may not take place immediately.
+/// For this update to occur faster than usual, call `flush` after the attribute is changed.
+///
+/// # Thread Safety
+///
+/// Like any other IUP function this is **not** safe to call from a thread other than the one
+/// running `with_iup`.
+pub fn flush() {
+    unsafe { iup_sys::IupFlush() };
+}
+
+/// Terminates the current message loop.
+///
+/// It has the same effect of a callback returning `CallbackReturn::Close`, that is, `with_iup`
+/// (or the current `loop_step` iteration) returns as soon as the control goes back to the loop.
+pub fn exit_loop() {
+    unsafe { iup_sys::IupExitLoop() };
+}
+
+/// Returns the interface element that has the keyboard focus or `None` when the focus is
+/// outside the application.
+pub fn focused_element() -> Option<Handle> {
+    match unsafe { iup_sys::IupGetFocus() } {
+        ptr if ptr.is_null() => None,
+        ptr => Some(Handle::from_raw(ptr)),
+    }
+}
+
+/// Whether IUP is currently initialized by `with_iup`.
+static IUP_OPEN: AtomicBool = AtomicBool::new(false);
+
+/// Checks whether IUP is currently initialized.
+fn is_open() -> bool {
+    IUP_OPEN.load(Ordering::SeqCst)
+}
+
+/// The minimum IUP version (as returned by `version_number`) required by this binding, IUP 3.12.
+pub const MIN_VERSION_NUMBER: i32 = 312000;
+
+/// Returns a string with the IUP version number, such as `"3.12"`.
 pub fn version() -> String {
     string_from_cstr!(unsafe { iup_sys::IupVersion() })
 }
 
-/// Returns a number indicating the IUP version.
+/// Returns the major, minor and patch numbers of the IUP version the application is running on.
+///
+/// Those are queries to the shared library itself and thus can be used even outside `with_iup`,
+/// for instance, to detect whether a feature is available before initializing the toolkit.
+pub fn version_tuple() -> (u32, u32, u32) {
+    let number = version_number() as u32;
+    let patch = version().split('.').nth(2).and_then(|s| s.parse().ok()).unwrap_or(0);
+    (number / 100000, (number / 1000) % 100, patch)
+}
+
+/// Returns a number indicating the IUP version, such as `312000` for IUP 3.12.
 pub fn version_number() -> i32 {
     unsafe { iup_sys::IupVersionNumber() as i32 }
 }
@@ -0,0 +1,43 @@
+use iup_sys;
+use std::ptr;
+use std::ffi::CString;
+
+use Element;
+
+/// An item of a menu.
+///
+/// See the [IUP Item Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupitem.html
+pub struct MenuItem(*mut iup_sys::Ihandle);
+
+impl MenuItem {
+    /// Creates a menu item with the specified text.
+    ///
+    /// A `&` before a character defines it as the mnemonic of the item.
+    pub fn new<S: Into<String>>(title: S) -> MenuItem {
+        let ctitle = CString::new(title.into()).unwrap();
+        unsafe { MenuItem::from_raw(iup_sys::IupItem(ctitle.as_ptr(), ptr::null())) }
+    }
+
+    /// Sets whether the item is enabled.
+    pub fn set_active(&mut self, active: bool) -> Self {
+        self.set_attrib("ACTIVE", if active { "YES" } else { "NO" })
+    }
+
+    /// Checks whether the item is enabled.
+    pub fn is_active(&self) -> bool {
+        self.attrib_bool("ACTIVE").unwrap_or(true)
+    }
+}
+
+impl_element!(MenuItem, "item");
+impl ::element::Node for MenuItem {}
+impl ::callback::MapCb for MenuItem {}
+impl ::callback::UnmapCb for MenuItem {}
+impl ::callback::HelpCb for MenuItem {}
+impl ::attribute::TitleAttribute for MenuItem {}
+
+/// Action generated when the item is selected.
+///
+/// `CallbackReturn::Close` will be processed.
+impl ::callback::Action for MenuItem {}
@@ -0,0 +1,62 @@
+//! Menus and their items.
+//!
+//! A `Menu` can be either associated with a dialog (`Dialog::set_menu`) to be its menu bar or
+//! shown anywhere on the screen with `Menu::popup`.
+//!
+//! See the [IUP Menu Documentation][1].
+//! [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupmenu.html
+
+use iup_sys;
+
+use Handle;
+use Element;
+use dialog::DialogPos;
+
+pub mod item;
+pub mod submenu;
+pub mod separator;
+
+pub use self::item::MenuItem;
+pub use self::submenu::Submenu;
+pub use self::separator::MenuSeparator;
+
+/// A menu of items, submenus and separators.
+///
+/// # Ownership
+///
+/// Menus associated with a dialog are automatically destroyed with the dialog, popup menus
+/// must be destroyed manually.
+///
+/// See the [IUP Menu Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupmenu.html
+pub struct Menu(*mut iup_sys::Ihandle);
+
+impl Menu {
+    /// Creates a menu with the specified items, submenus and separators.
+    pub fn new<A>(elems: A) -> Menu where A: AsRef<[Handle]> {
+        let mut carray = slice_to_ih_array!(elems.as_ref());
+        unsafe { Menu::from_raw(iup_sys::IupMenuv(carray.as_mut_ptr())) }
+    }
+
+    /// Shows the menu as a popup at the specified position on the screen.
+    ///
+    /// Returns automatically after a menu item is selected or the menu is dismissed.
+    ///
+    /// # Panics
+    /// Panics if `x` is either `Bottom`, `Top` or `Current` or if `y` is either `Left`, `Right`
+    /// or `Current`.
+    pub fn popup(&mut self, x: DialogPos, y: DialogPos) -> Result<Self, Self> {
+        assert!(x != DialogPos::Current && y != DialogPos::Current);
+        match unsafe { iup_sys::IupPopup(self.raw(), x.to_raw_x(), y.to_raw_y()) } {
+            iup_sys::IUP_NOERROR => Ok(*self),
+            _ => Err(*self),
+        }
+    }
+}
+
+impl_element!(Menu, "menu");
+impl ::element::Node for Menu {}
+impl ::element::Container for Menu {}
+impl ::callback::MapCb for Menu {}
+impl ::callback::UnmapCb for Menu {}
+impl ::attribute::BgColorAttribute for Menu {}
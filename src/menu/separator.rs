@@ -0,0 +1,25 @@
+use iup_sys;
+
+use Element;
+
+/// A line separating the items of a menu.
+///
+/// See the [IUP Separator Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupseparator.html
+pub struct MenuSeparator(*mut iup_sys::Ihandle);
+
+impl MenuSeparator {
+    /// Creates a menu separator.
+    pub fn new() -> MenuSeparator {
+        unsafe { MenuSeparator::from_raw(iup_sys::IupSeparator()) }
+    }
+}
+
+impl Default for MenuSeparator {
+    fn default() -> MenuSeparator {
+        MenuSeparator::new()
+    }
+}
+
+impl_element!(MenuSeparator, "separator");
+impl ::element::Node for MenuSeparator {}
@@ -0,0 +1,34 @@
+use iup_sys;
+use std::ffi::CString;
+
+use Element;
+use menu::Menu;
+
+/// A menu item that, when selected, opens another menu.
+///
+/// See the [IUP Submenu Documentation][1].
+/// [1]: http://webserver2.tecgraf.puc-rio.br/iup/en/elem/iupsubmenu.html
+pub struct Submenu(*mut iup_sys::Ihandle);
+
+impl Submenu {
+    /// Creates a submenu with the specified text that opens the specified menu.
+    ///
+    /// A `&` before a character defines it as the mnemonic of the submenu.
+    pub fn new<S: Into<String>>(title: S, menu: Menu) -> Submenu {
+        let ctitle = CString::new(title.into()).unwrap();
+        unsafe { Submenu::from_raw(iup_sys::IupSubmenu(ctitle.as_ptr(), menu.raw())) }
+    }
+
+    /// Sets whether the submenu is enabled.
+    pub fn set_active(&mut self, active: bool) -> Self {
+        self.set_attrib("ACTIVE", if active { "YES" } else { "NO" })
+    }
+}
+
+impl_element!(Submenu, "submenu");
+impl ::element::Node for Submenu {}
+impl ::element::Container for Submenu {}
+impl ::callback::MapCb for Submenu {}
+impl ::callback::UnmapCb for Submenu {}
+impl ::callback::HelpCb for Submenu {}
+impl ::attribute::TitleAttribute for Submenu {}
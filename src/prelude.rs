@@ -14,18 +14,26 @@
 
 // Common Types
 pub use ::Orientation;
+pub use attribute::{Color, Size, ScrollBar, Cursor};
 pub use dialog::{Dialog, DialogPos};
 
 // Common Traits
 pub use element::{Element, Widget, Container, Node, ConvertXYToPos};
 pub use image::ImageElement;
 pub use dialog::DialogElement;
+pub use control::TextElement;
+pub use attribute::{TitleAttribute, BgColorAttribute, FgColorAttribute, SizeAttribute};
+pub use attribute::{ScrollBarAttribute, CursorAttribute, TipAttribute, DropTargetAttribute};
 
 // Callbacks
 pub use callback::{CallbackReturn, Action, ActionCb, DestroyCb};
 pub use callback::{MapCb, UnmapCb, GetFocusCb, KillFocusCb, EnterWindowCb, LeaveWindowCb, HelpCb};
 pub use callback::{CaretCb, SpinCb, ValueChangedCb, DropFilesCb};
-pub use callback::{CloseCb, MoveCb, ResizeCb};
-pub use callback::button::{ButtonCb, MotionCb};
-pub use control::{TextAction, ToggleAction, ListAction};
+pub use callback::{CloseCb, MoveCb, ResizeCb, ScrollCb};
+pub use callback::button::{ButtonCb, MotionCb, WheelCb};
+pub use callback::key::{KAnyCb, KeyActionCb, Key};
+pub use control::{TextAction, ToggleAction, ListAction, CanvasAction};
+pub use control::{ListDblClickCb, TabChangeCb, TreeSelectionCb, MatrixClickCb};
+pub use control::ValValueChangedCb;
+pub use layout::OpenCloseCb;
 pub use dialog::{CopyDataCb, MdiActivateCb, ShowCb, TrayClickCb};
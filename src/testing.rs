@@ -0,0 +1,80 @@
+//! Recording and playback of user input for automated GUI testing.
+//!
+//! The input events (mouse and keyboard) received by the application can be recorded into a file
+//! and later played back, reproducing exactly the same interaction.
+//!
+//! # Example
+//! ```ignore
+//! // Record a session ...
+//! testing::start_record("session.rec", RecordMode::Text).unwrap();
+//! // ... interact with the application ...
+//! testing::stop_record();
+//!
+//! // ... and replay it in a later run.
+//! testing::play_input("session.rec").unwrap();
+//! ```
+use iup_sys;
+use std::path::Path;
+use std::ffi::CString;
+use std::ptr;
+
+/// The file format used by `start_record`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RecordMode {
+    /// A compact binary file.
+    Binary,
+    /// A human readable text file, which can be edited by hand.
+    Text,
+}
+
+impl RecordMode {
+    fn to_raw(self) -> i32 {
+        match self {
+            RecordMode::Binary => iup_sys::IUP_RECBINARY,
+            RecordMode::Text => iup_sys::IUP_RECTEXT,
+        }
+    }
+}
+
+fn path_to_cstring(path: &Path) -> Result<CString, String> {
+    let str = path.to_str().ok_or_else(|| "Failed to convert Path to string".to_string())?;
+    Ok(CString::new(str).unwrap())
+}
+
+/// Starts recording the user input into the specified file, replacing it.
+///
+/// The recording stops when `stop_record` is called or when IUP is closed.
+pub fn start_record<P: AsRef<Path>>(path: P, mode: RecordMode) -> Result<(), String> {
+    let cpath = path_to_cstring(path.as_ref())?;
+    match unsafe { iup_sys::IupRecordInput(cpath.as_ptr(), mode.to_raw()) } {
+        iup_sys::IUP_NOERROR => Ok(()),
+        _ => Err(format!("Failed to open {} for recording", path.as_ref().display())),
+    }
+}
+
+/// Stops the current recording, closing the file.
+pub fn stop_record() {
+    unsafe { iup_sys::IupRecordInput(ptr::null(), 0) };
+}
+
+/// Starts playing the user input recorded in the specified file.
+///
+/// The playback does not block, the events are sent to the application as the main loop runs
+/// and the playback stops by itself at the end of the file.
+pub fn play_input<P: AsRef<Path>>(path: P) -> Result<(), String> {
+    let cpath = path_to_cstring(path.as_ref())?;
+    match unsafe { iup_sys::IupPlayInput(cpath.as_ptr()) } {
+        iup_sys::IUP_NOERROR => Ok(()),
+        _ => Err(format!("Failed to open {} for playing", path.as_ref().display())),
+    }
+}
+
+/// Pauses the current playback or continues it if it is paused.
+pub fn toggle_pause_play() {
+    unsafe { iup_sys::IupPlayInput(cstr!("")) };
+}
+
+/// Stops the current playback.
+pub fn stop_play() {
+    unsafe { iup_sys::IupPlayInput(ptr::null()) };
+}